@@ -31,6 +31,7 @@ pub enum BusSpeed {
 ///
 /// LLR-A429-010: SDI field encoding (bits 9-10)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Sdi {
     /// All systems
@@ -61,6 +62,7 @@ impl TryFrom<u8> for Sdi {
 ///
 /// LLR-A429-011: SSM field encoding (bits 30-31)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Ssm {
     /// Normal operation
@@ -87,6 +89,14 @@ impl TryFrom<u8> for Ssm {
     }
 }
 
+/// Extract a `len`-bit field starting at bit `lsb` (0-indexed) from `raw`.
+///
+/// LLR-A429-005: Single bit-range extractor shared by all field accessors
+#[inline]
+fn get_bits(raw: u32, lsb: u8, len: u8) -> u32 {
+    (raw >> lsb) & ((1u32 << len) - 1)
+}
+
 /// ARINC 429 Word structure
 ///
 /// HLR-A429-010: 32-bit word format
@@ -97,7 +107,8 @@ impl TryFrom<u8> for Ssm {
 /// - Bits 11-29: Data field
 /// - Bits 30-31: SSM (Sign/Status Matrix)
 /// - Bit 32: Parity (odd)
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Word {
     raw: u32,
 }
@@ -145,22 +156,22 @@ impl Word {
     ///
     /// HLR-A429-003: Label extraction
     pub fn label(&self) -> u8 {
-        Self::reverse_label((self.raw & 0xFF) as u8)
+        Self::reverse_label(get_bits(self.raw, 0, 8) as u8)
     }
 
     /// Get the SDI field
     pub fn sdi(&self) -> Sdi {
-        Sdi::try_from(((self.raw >> 8) & 0x03) as u8).unwrap()
+        Sdi::try_from(get_bits(self.raw, 8, 2) as u8).unwrap()
     }
 
     /// Get the data field (19 bits)
     pub fn data(&self) -> u32 {
-        (self.raw >> 10) & 0x7FFFF
+        get_bits(self.raw, 10, 19)
     }
 
     /// Get the SSM field
     pub fn ssm(&self) -> Ssm {
-        Ssm::try_from(((self.raw >> 29) & 0x03) as u8).unwrap()
+        Ssm::try_from(get_bits(self.raw, 29, 2) as u8).unwrap()
     }
 
     /// Get raw 32-bit word
@@ -168,6 +179,41 @@ impl Word {
         self.raw
     }
 
+    /// Serialize the word into its 32 bits in transmission order.
+    ///
+    /// LLR-A429-027: On-the-wire bit ordering
+    ///
+    /// Index 0 is the first bit on the wire (label LSB) and index 31 is the
+    /// parity bit, so a captured bus log can be round-tripped exactly as
+    /// transmitted via [`from_wire_bits`](Word::from_wire_bits).
+    pub fn to_wire_bits(&self) -> [bool; 32] {
+        let mut bits = [false; 32];
+        for (i, bit) in bits.iter_mut().enumerate() {
+            *bit = self.raw & (1 << i) != 0;
+        }
+        bits
+    }
+
+    /// Reconstruct a word from 32 bits in transmission order.
+    ///
+    /// SAF-A429-001: Parity is validated on reconstruction
+    pub fn from_wire_bits(bits: &[bool; 32]) -> Result<Self, Arinc429Error> {
+        let mut raw = 0u32;
+        for (i, &bit) in bits.iter().enumerate() {
+            if bit {
+                raw |= 1 << i;
+            }
+        }
+        Word::from_raw(raw)
+    }
+
+    /// Rebuild this word with a different SSM (parity recomputed)
+    ///
+    /// LLR-A429-026: Used to downgrade stale cached data to "no computed data"
+    pub fn with_ssm(&self, ssm: Ssm) -> Word {
+        Word::new(self.label(), self.sdi(), self.data(), ssm)
+    }
+
     /// Verify parity
     ///
     /// SAF-A429-001: Parity verification
@@ -186,60 +232,131 @@ impl Word {
     }
 }
 
+/// BNR sign/status matrix interpretation (bits 30-31).
+///
+/// LLR-A429-035: BNR SSM carries sign as well as status
+///
+/// For BNR labels the two SSM bits encode the sign of the value together with
+/// the validity status: `Plus`/`Minus` for live data, `NoComputedData` and
+/// `FunctionalTest` for non-operational words. The bit values coincide with
+/// [`Ssm`] so the pair can be placed into a [`Word`] unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum BnrSsm {
+    /// Positive value (plus/north/east/right/to/above)
+    Plus = 0b00,
+    /// No computed data
+    NoComputedData = 0b01,
+    /// Functional test
+    FunctionalTest = 0b10,
+    /// Negative value (minus/south/west/left/from/below)
+    Minus = 0b11,
+}
+
+impl BnrSsm {
+    /// Map to the raw SSM pair placed in the word.
+    fn to_ssm(self) -> Ssm {
+        match self {
+            BnrSsm::Plus => Ssm::Normal,
+            BnrSsm::NoComputedData => Ssm::NoComputedData,
+            BnrSsm::FunctionalTest => Ssm::FunctionalTest,
+            BnrSsm::Minus => Ssm::FailureWarning,
+        }
+    }
+
+    /// Recover the BNR interpretation from a word's SSM pair.
+    fn from_ssm(ssm: Ssm) -> Self {
+        match ssm {
+            Ssm::Normal => BnrSsm::Plus,
+            Ssm::NoComputedData => BnrSsm::NoComputedData,
+            Ssm::FunctionalTest => BnrSsm::FunctionalTest,
+            Ssm::FailureWarning => BnrSsm::Minus,
+        }
+    }
+}
+
 /// BNR (Binary Number Representation) data encoding
 ///
 /// LLR-A429-030: BNR format support
+///
+/// A BNR field occupies an arbitrary run of the 19-bit data field, from `msb`
+/// down to `lsb` (both given as word bit positions 11-29), holding
+/// `msb - lsb + 1` magnitude bits with any remaining data bits zero-padded.
+/// Per ARINC 429 the sign is carried in the SSM rather than the data field.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BnrFormat {
     /// Most significant bit position (11-29)
     pub msb: u8,
+    /// Least significant bit position (11-29)
+    pub lsb: u8,
     /// Resolution (LSB value)
     pub resolution: f32,
-    /// Signed or unsigned
+    /// Signed (sign carried in SSM) or unsigned
     pub signed: bool,
 }
 
 impl BnrFormat {
-    /// Encode a floating-point value to BNR
+    /// Number of magnitude bits the field occupies.
+    fn significant_bits(&self) -> u8 {
+        self.msb - self.lsb + 1
+    }
+
+    /// Encode a floating-point value to a BNR data field and its SSM.
     ///
-    /// LLR-A429-031: BNR encoding
-    pub fn encode(&self, value: f32) -> u32 {
-        let bits = self.msb - 10; // Data field starts at bit 11
-        let max_val = (1u32 << bits) - 1;
+    /// LLR-A429-031: BNR encoding with sign/status in the SSM
+    ///
+    /// The magnitude is scaled by `resolution` and placed at `lsb`. A value
+    /// whose magnitude exceeds the field capacity is clamped and flagged
+    /// [`BnrSsm::NoComputedData`] instead of silently wrapping; otherwise the
+    /// SSM carries the sign (`Plus`/`Minus`) for a signed field or
+    /// [`Ssm::Normal`] for an unsigned one.
+    pub fn encode(&self, value: f32) -> (u32, Ssm) {
+        let shift = self.lsb - 11;
+        let max_mag = (1u32 << self.significant_bits()) - 1;
 
         let scaled = (value / self.resolution) as i32;
+        let mag = scaled.unsigned_abs();
 
-        if self.signed {
-            // LLR-A429-032: Two's complement for signed
-            (scaled as u32) & max_val
-        } else {
-            (scaled as u32).min(max_val)
+        if mag > max_mag {
+            // LLR-A429-032: Out-of-range values are clamped and marked invalid
+            let data = (max_mag << shift) & 0x7FFFF;
+            return (data, Ssm::NoComputedData);
         }
+
+        let ssm = if self.signed {
+            if scaled < 0 { BnrSsm::Minus } else { BnrSsm::Plus }.to_ssm()
+        } else {
+            Ssm::Normal
+        };
+        ((mag << shift) & 0x7FFFF, ssm)
     }
 
-    /// Decode BNR to floating-point value
+    /// Decode a BNR data field using the word's SSM.
     ///
-    /// LLR-A429-033: BNR decoding
-    pub fn decode(&self, data: u32) -> f32 {
-        let bits = self.msb - 10;
-
-        if self.signed {
-            // LLR-A429-034: Sign extension for signed values
-            let sign_bit = 1u32 << (bits - 1);
-            let value = if data & sign_bit != 0 {
-                (data | !((1u32 << bits) - 1)) as i32
-            } else {
-                data as i32
-            };
-            value as f32 * self.resolution
-        } else {
-            data as f32 * self.resolution
+    /// LLR-A429-033: BNR decoding with sign recovered from the SSM
+    ///
+    /// Returns the engineering value and whether the word was flagged
+    /// non-operational (functional-test or no-computed-data). For a signed
+    /// field the sign is taken from the SSM.
+    pub fn decode(&self, data: u32, ssm: Ssm) -> (f32, bool) {
+        let shift = self.lsb - 11;
+        let max_mag = (1u32 << self.significant_bits()) - 1;
+        let mag = (data >> shift) & max_mag;
+
+        let mut value = mag as f32 * self.resolution;
+        if self.signed && BnrSsm::from_ssm(ssm) == BnrSsm::Minus {
+            value = -value;
         }
+
+        let flagged = matches!(ssm, Ssm::FunctionalTest | Ssm::NoComputedData);
+        (value, flagged)
     }
 }
 
 /// BCD (Binary Coded Decimal) data encoding
 ///
 /// LLR-A429-040: BCD format support
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcdFormat {
     /// Number of digits
     pub digits: u8,
@@ -279,6 +396,45 @@ impl BcdFormat {
     }
 }
 
+/// Discrete / maintenance-word data encoding
+///
+/// LLR-A429-045: Discrete format support
+///
+/// Individual bits of the data field are independent boolean states rather than
+/// a single number. `bit_offsets` addresses each state relative to the data
+/// field (0 = word bit 11); the `i`th boolean maps to `bit_offsets[i]`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiscreteFormat<const N: usize> {
+    /// Data-field-relative bit position of each discrete state
+    pub bit_offsets: [u8; N],
+}
+
+impl<const N: usize> DiscreteFormat<N> {
+    /// Pack boolean states into a data field.
+    ///
+    /// LLR-A429-046: Discrete encoding
+    ///
+    /// Missing trailing states default to `false`; extra states are ignored.
+    pub fn encode(&self, states: &[bool]) -> u32 {
+        let mut data = 0u32;
+        for (i, &offset) in self.bit_offsets.iter().enumerate() {
+            if states.get(i).copied().unwrap_or(false) {
+                data |= 1 << offset;
+            }
+        }
+        data & 0x7FFFF
+    }
+
+    /// Unpack a data field into its discrete states, in `bit_offsets` order.
+    ///
+    /// LLR-A429-047: Discrete decoding
+    pub fn decode(&self, data: u32) -> impl Iterator<Item = bool> + '_ {
+        self.bit_offsets
+            .iter()
+            .map(move |&offset| data & (1 << offset) != 0)
+    }
+}
+
 /// ARINC 429 driver errors
 ///
 /// SAF-A429-010: Error enumeration
@@ -359,6 +515,219 @@ impl<HW: TxHardware> Tx<HW> {
     }
 }
 
+impl<HW: TxHardwareAsync> Tx<HW> {
+    /// Transmit a word, suspending until the FIFO can accept it
+    ///
+    /// HLR-A429-022: Asynchronous word transmission
+    /// SAF-A429-020: Transmit shall verify word format
+    ///
+    /// Unlike [`send`](Tx::send) this never returns [`Arinc429Error::NotReady`];
+    /// it awaits the hardware readiness future so the task can yield to the
+    /// executor instead of busy-looping the FIFO.
+    pub async fn send_async(&mut self, word: Word) -> Result<(), Arinc429Error> {
+        // LLR-A429-072: Await FIFO space via interrupt/waker registration
+        self.hw.wait_tx_ready().await;
+        self.hw.write_word(word.raw());
+        Ok(())
+    }
+}
+
+/// A scheduled periodic transmission.
+///
+/// LLR-A429-110: Per-label refresh entry
+#[derive(Debug, Clone, Copy)]
+struct ScheduleEntry {
+    /// Label (standard octal form) this entry refreshes
+    label: u8,
+    /// Source/destination identifier
+    sdi: Sdi,
+    /// Word transmitted on each refresh
+    word: Word,
+    /// Refresh period in ticks
+    period: u32,
+    /// Permitted lateness (ticks) before a missed slot counts as an overrun
+    jitter_tolerance: u32,
+    /// Tick at which this entry is next due
+    next_due: u32,
+    /// Count of refreshes that could not be sent within tolerance
+    overruns: u32,
+}
+
+/// Periodic transmission scheduler for an ARINC 429 bus.
+///
+/// HLR-A429-040: Fixed-rate label transmission
+///
+/// Owns a table of `(Word, period, jitter_tolerance)` entries keyed by
+/// label+SDI. On each [`poll`](TxScheduler::poll) every entry whose
+/// `next_due <= now` is emitted through [`Tx::send`] and rescheduled one period
+/// ahead, respecting the `gap_bits` spacing so the bus is never driven above
+/// the bandwidth of the configured [`BusSpeed`].
+///
+/// Ticks are taken to be bit periods at the configured bus speed, so the
+/// per-word bit budget (`32 + gap_bits`) directly bounds how often a word may
+/// be placed on the wire. The table has a fixed capacity `N` to avoid an
+/// allocator in `no_std`; entries are scanned in due order rather than held in
+/// an allocator-backed heap.
+pub struct TxScheduler<const N: usize> {
+    entries: [Option<ScheduleEntry>; N],
+    /// Bit budget of one word plus its inter-word gap
+    word_ticks: u32,
+    /// Earliest tick at which the bus is free for the next word
+    next_slot_free: u32,
+}
+
+impl<const N: usize> TxScheduler<N> {
+    /// Create a scheduler for a bus with the given TX configuration.
+    ///
+    /// LLR-A429-111: Derive the per-word bit budget from `gap_bits`
+    pub fn new(config: &TxConfig) -> Self {
+        Self {
+            entries: [None; N],
+            // 32 data bits plus the configured inter-word gap
+            word_ticks: 32 + config.gap_bits as u32,
+            next_slot_free: 0,
+        }
+    }
+
+    /// Register (or replace) a periodic entry keyed by label+SDI.
+    ///
+    /// LLR-A429-112: Schedule a label at a fixed refresh rate
+    ///
+    /// The first transmission is due at `start_tick`. Returns
+    /// [`Arinc429Error::InvalidConfig`] if the table is full or the period is
+    /// zero.
+    pub fn schedule(
+        &mut self,
+        word: Word,
+        period: u32,
+        jitter_tolerance: u32,
+        start_tick: u32,
+    ) -> Result<(), Arinc429Error> {
+        if period == 0 {
+            return Err(Arinc429Error::InvalidConfig);
+        }
+
+        let entry = ScheduleEntry {
+            label: word.label(),
+            sdi: word.sdi(),
+            word,
+            period,
+            jitter_tolerance,
+            next_due: start_tick,
+            overruns: 0,
+        };
+
+        // Replace an existing entry for the same label+SDI if present.
+        if let Some(slot) = self.find_slot(entry.label, entry.sdi) {
+            let overruns = self.entries[slot].map(|e| e.overruns).unwrap_or(0);
+            self.entries[slot] = Some(ScheduleEntry { overruns, ..entry });
+            return Ok(());
+        }
+
+        // Otherwise take the first free slot.
+        for slot in self.entries.iter_mut() {
+            if slot.is_none() {
+                *slot = Some(entry);
+                return Ok(());
+            }
+        }
+
+        Err(Arinc429Error::InvalidConfig)
+    }
+
+    /// Emit every word due at `now`, rescheduling each one period ahead.
+    ///
+    /// LLR-A429-113: Drain due entries in next-due order
+    ///
+    /// Entries are sent oldest-due first, never closer together than the
+    /// per-word bit budget: a virtual bus-free cursor advances one bit budget
+    /// per emitted word, so several words due at the same `now` drain across
+    /// one poll while still respecting the bandwidth gate. A refresh pushed
+    /// past its jitter tolerance — whether because the FIFO is full or because
+    /// the bandwidth gate held its slot — is counted as a per-label overrun;
+    /// any such miss surfaces as [`Arinc429Error::GapError`] once the due set
+    /// has drained. Entries still inside tolerance are left for a later poll.
+    /// Returns the number of words transmitted.
+    pub fn poll<HW: TxHardware>(
+        &mut self,
+        tx: &mut Tx<HW>,
+        now: u32,
+    ) -> Result<usize, Arinc429Error> {
+        let mut sent = 0;
+        let mut gap_miss = false;
+        // LLR-A429-114: Virtual bus-free cursor, never earlier than `now`.
+        let mut slot_free = self.next_slot_free.max(now);
+
+        while let Some(slot) = self.earliest_due(now) {
+            let entry = self.entries[slot].as_mut().expect("slot is due");
+            let deadline = entry.next_due.wrapping_add(entry.jitter_tolerance);
+
+            // The bus may still be busy with earlier words emitted this poll.
+            if slot_free > deadline {
+                entry.overruns = entry.overruns.saturating_add(1);
+                entry.next_due = entry.next_due.wrapping_add(entry.period);
+                gap_miss = true;
+                continue;
+            }
+
+            if tx.send(entry.word).is_ok() {
+                sent += 1;
+                entry.next_due = entry.next_due.wrapping_add(entry.period);
+                slot_free = slot_free.wrapping_add(self.word_ticks);
+                continue;
+            }
+
+            // FIFO could not accept the word. If we are still inside the jitter
+            // tolerance, retry on a later poll; otherwise this slot is lost.
+            if now <= deadline {
+                break;
+            }
+            entry.overruns = entry.overruns.saturating_add(1);
+            entry.next_due = entry.next_due.wrapping_add(entry.period);
+            gap_miss = true;
+        }
+
+        self.next_slot_free = slot_free;
+        if gap_miss {
+            return Err(Arinc429Error::GapError);
+        }
+        Ok(sent)
+    }
+
+    /// Per-label overrun count (refreshes that missed their tolerance window).
+    ///
+    /// LLR-A429-115: Overrun monitoring
+    pub fn overruns(&self, label: u8, sdi: Sdi) -> u32 {
+        self.find_slot(label, sdi)
+            .and_then(|slot| self.entries[slot])
+            .map(|e| e.overruns)
+            .unwrap_or(0)
+    }
+
+    /// Slot index of the due entry with the smallest `next_due`, if any.
+    fn earliest_due(&self, now: u32) -> Option<usize> {
+        let mut best: Option<(usize, u32)> = None;
+        for (i, slot) in self.entries.iter().enumerate() {
+            if let Some(entry) = slot {
+                if entry.next_due <= now {
+                    match best {
+                        Some((_, due)) if due <= entry.next_due => {}
+                        _ => best = Some((i, entry.next_due)),
+                    }
+                }
+            }
+        }
+        best.map(|(i, _)| i)
+    }
+
+    /// Slot index of the entry keyed by `label`+`sdi`, if present.
+    fn find_slot(&self, label: u8, sdi: Sdi) -> Option<usize> {
+        self.entries.iter().position(|slot| {
+            matches!(slot, Some(e) if e.label == label && e.sdi == sdi)
+        })
+    }
+}
+
 /// ARINC 429 receiver
 ///
 /// HLR-A429-030: Receive capability
@@ -407,6 +776,201 @@ impl<HW: RxHardware> Rx<HW> {
     }
 }
 
+impl<HW: RxHardwareAsync> Rx<HW> {
+    /// Receive a word, suspending until one arrives
+    ///
+    /// HLR-A429-032: Asynchronous word reception
+    /// SAF-A429-001: Verify parity on receive
+    ///
+    /// Unlike [`receive`](Rx::receive) this never yields `Ok(None)`; it awaits
+    /// the next word future, so the task sleeps until the RX interrupt fires.
+    pub async fn receive_async(&mut self) -> Result<Word, Arinc429Error> {
+        // LLR-A429-083: Await the next word via interrupt/waker registration
+        let raw = self.hw.wait_rx_word().await;
+
+        // SAF-A429-001: Validate parity
+        Word::from_raw(raw)
+    }
+}
+
+/// A cached word annotated with its freshness at the time of the query.
+///
+/// LLR-A429-120: Freshness-qualified reception result
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FreshWord {
+    /// Most recent word for the label+SDI. When `fresh` is `false` the SSM has
+    /// been downgraded to [`Ssm::NoComputedData`] so a consumer that ignores
+    /// the flag still cannot treat stale data as valid.
+    pub word: Word,
+    /// Ticks elapsed since the word arrived.
+    pub age: u32,
+    /// `true` while `age` is within the label's configured timeout.
+    pub fresh: bool,
+}
+
+/// Most-recent-value cache keyed by label+SDI.
+///
+/// HLR-A429-050: Per-label signal freshness
+///
+/// Stores the latest [`Word`] received for each label+SDI together with the
+/// tick it arrived and a per-label timeout. [`get`](RxCache::get) reports a
+/// value older than its timeout as stale and downgrades its SSM to
+/// [`Ssm::NoComputedData`]. The inter-arrival interval is tracked per label so
+/// a caller can detect a source that has gone silent or is transmitting faster
+/// than expected. This complements the FIFO-overflow monitoring on
+/// [`Rx::fifo_count`].
+///
+/// As with [`TxScheduler`] the table has a fixed capacity `N` to avoid an
+/// allocator in `no_std`.
+pub struct RxCache<const N: usize> {
+    entries: [Option<CacheEntry>; N],
+    /// Timeout applied to labels that have not been given an explicit one.
+    default_timeout: u32,
+}
+
+/// A cached reception for one label+SDI.
+///
+/// LLR-A429-121: Per-label cache entry
+#[derive(Debug, Clone, Copy)]
+struct CacheEntry {
+    /// Label (standard octal form) this entry caches
+    label: u8,
+    /// Source/destination identifier
+    sdi: Sdi,
+    /// Most recent word, or `None` if only a timeout has been configured
+    word: Option<Word>,
+    /// Tick at which `word` arrived
+    arrival: u32,
+    /// Staleness threshold in ticks
+    timeout: u32,
+    /// Ticks between the two most recent arrivals (0 until a second arrives)
+    last_interval: u32,
+}
+
+impl<const N: usize> RxCache<N> {
+    /// Create an empty cache using `default_timeout` for every label.
+    ///
+    /// LLR-A429-122: Cache construction
+    pub fn new(default_timeout: u32) -> Self {
+        Self {
+            entries: [None; N],
+            default_timeout,
+        }
+    }
+
+    /// Override the staleness timeout for one label+SDI.
+    ///
+    /// LLR-A429-123: Per-label timeout configuration
+    ///
+    /// May be called before any word has been received. Returns
+    /// [`Arinc429Error::InvalidConfig`] if the table is full.
+    pub fn set_timeout(
+        &mut self,
+        label: u8,
+        sdi: Sdi,
+        timeout: u32,
+    ) -> Result<(), Arinc429Error> {
+        if let Some(slot) = self.find_slot(label, sdi) {
+            self.entries[slot].as_mut().expect("slot present").timeout = timeout;
+            return Ok(());
+        }
+
+        for slot in self.entries.iter_mut() {
+            if slot.is_none() {
+                *slot = Some(CacheEntry {
+                    label,
+                    sdi,
+                    word: None,
+                    arrival: 0,
+                    timeout,
+                    last_interval: 0,
+                });
+                return Ok(());
+            }
+        }
+
+        Err(Arinc429Error::InvalidConfig)
+    }
+
+    /// Record a freshly received word at tick `now`.
+    ///
+    /// LLR-A429-124: Update the most-recent value and inter-arrival interval
+    ///
+    /// Returns [`Arinc429Error::Overflow`] if the word is for a new label+SDI
+    /// and the table is full.
+    pub fn update(&mut self, word: Word, now: u32) -> Result<(), Arinc429Error> {
+        let (label, sdi) = (word.label(), word.sdi());
+
+        if let Some(slot) = self.find_slot(label, sdi) {
+            let entry = self.entries[slot].as_mut().expect("slot present");
+            if entry.word.is_some() {
+                entry.last_interval = now.wrapping_sub(entry.arrival);
+            }
+            entry.word = Some(word);
+            entry.arrival = now;
+            return Ok(());
+        }
+
+        for slot in self.entries.iter_mut() {
+            if slot.is_none() {
+                *slot = Some(CacheEntry {
+                    label,
+                    sdi,
+                    word: Some(word),
+                    arrival: now,
+                    timeout: self.default_timeout,
+                    last_interval: 0,
+                });
+                return Ok(());
+            }
+        }
+
+        Err(Arinc429Error::Overflow)
+    }
+
+    /// Fetch the latest word for a label+SDI, qualified by freshness at `now`.
+    ///
+    /// LLR-A429-125: Freshness-qualified read
+    ///
+    /// Returns `None` if nothing has been received for the key. A value older
+    /// than its timeout is returned with `fresh == false` and its SSM
+    /// downgraded to [`Ssm::NoComputedData`].
+    pub fn get(&self, label: u8, sdi: Sdi, now: u32) -> Option<FreshWord> {
+        let entry = self.find_slot(label, sdi).and_then(|slot| self.entries[slot])?;
+        let word = entry.word?;
+        let age = now.wrapping_sub(entry.arrival);
+        let fresh = age <= entry.timeout;
+        let word = if fresh {
+            word
+        } else {
+            // SAF-A429-011: Stale data must not masquerade as valid
+            word.with_ssm(Ssm::NoComputedData)
+        };
+        Some(FreshWord { word, age, fresh })
+    }
+
+    /// Ticks between the two most recent receptions for a label+SDI.
+    ///
+    /// LLR-A429-126: Receive-rate monitoring
+    ///
+    /// Returns `None` until at least two words have arrived. A value far above
+    /// the expected period indicates a source going silent; far below it
+    /// indicates a source transmitting too fast.
+    pub fn interval(&self, label: u8, sdi: Sdi) -> Option<u32> {
+        self.find_slot(label, sdi)
+            .and_then(|slot| self.entries[slot])
+            .filter(|e| e.last_interval != 0)
+            .map(|e| e.last_interval)
+    }
+
+    /// Slot index of the entry keyed by `label`+`sdi`, if present.
+    fn find_slot(&self, label: u8, sdi: Sdi) -> Option<usize> {
+        self.entries.iter().position(|slot| {
+            matches!(slot, Some(e) if e.label == label && e.sdi == sdi)
+        })
+    }
+}
+
 /// Hardware abstraction trait for TX
 ///
 /// LLR-A429-090: Hardware interface
@@ -426,6 +990,30 @@ pub trait RxHardware {
     fn rx_fifo_count(&self) -> usize;
 }
 
+/// Async hardware abstraction trait for TX
+///
+/// LLR-A429-092: Waker-backed TX readiness for embassy/RTIC executors
+///
+/// Extends [`TxHardware`], so the blocking `tx_ready`/`write_word` API remains
+/// available on the same type and both execution models coexist.
+#[allow(async_fn_in_trait)]
+pub trait TxHardwareAsync: TxHardware {
+    /// Resolve once the TX FIFO can accept another word.
+    async fn wait_tx_ready(&mut self);
+}
+
+/// Async hardware abstraction trait for RX
+///
+/// LLR-A429-093: Waker-backed RX reception for embassy/RTIC executors
+///
+/// Extends [`RxHardware`], so the blocking `rx_available`/`read_word` API
+/// remains available on the same type and both execution models coexist.
+#[allow(async_fn_in_trait)]
+pub trait RxHardwareAsync: RxHardware {
+    /// Resolve with the next received raw word once one arrives.
+    async fn wait_rx_word(&mut self) -> u32;
+}
+
 /// Common ARINC 429 labels (subset)
 ///
 /// Reference: ARINC 429 Attachment 6
@@ -458,6 +1046,458 @@ pub mod labels {
     pub const DATE: u8 = 0o260;
 }
 
+/// Wire encoding of a label's data field.
+///
+/// LLR-A429-130: Label format discriminant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabelFormat {
+    /// Binary magnitude, sign carried in the SSM
+    Bnr,
+    /// Binary-coded decimal
+    Bcd,
+    /// Independent discrete bits
+    Discrete,
+}
+
+/// Declarative decode spec for one (label, equipment-id) pair.
+///
+/// LLR-A429-131: Label dictionary entry
+#[derive(Debug, Clone, Copy)]
+pub struct LabelDef {
+    /// Label (standard octal form)
+    pub label: u8,
+    /// Equipment identifier that disambiguates reused labels. `lookup` matches
+    /// it exactly (0 is not a wildcard); `decode_word` does not consult it.
+    pub equipment_id: u8,
+    /// Wire format of the data field
+    pub format: LabelFormat,
+    /// Least significant data bit (word bit 11-29)
+    pub lsb: u8,
+    /// Most significant data bit (word bit 11-29)
+    pub msb: u8,
+    /// Engineering value per LSB
+    pub resolution: f32,
+    /// Engineering units
+    pub units: &'static str,
+    /// Lower valid bound (engineering units)
+    pub min: f32,
+    /// Upper valid bound (engineering units)
+    pub max: f32,
+}
+
+/// A decoded label value with units and status.
+///
+/// LLR-A429-132: Engineering-unit decode result
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Decoded {
+    /// Engineering value
+    pub value: f32,
+    /// Units string from the dictionary
+    pub units: &'static str,
+    /// SSM of the source word
+    pub ssm: Ssm,
+    /// `true` when the SSM is operational and the value is within range
+    pub valid: bool,
+}
+
+/// Standard labels from ARINC 429 Attachment 6 (subset).
+///
+/// LLR-A429-133: Pre-populated dictionary entries
+///
+/// The BCD fields span a whole number of 4-bit digits; a 19-bit data field
+/// holds at most four digits, so the time/date labels carry the most
+/// significant four digits (`hhmm`/`ddmm`) — full `hhmmss`/`ddmmyy` resolution
+/// is split across additional labels on a real bus.
+const STANDARD_LABELS: &[LabelDef] = &[
+    LabelDef {
+        label: labels::LAT,
+        equipment_id: 0,
+        format: LabelFormat::Bnr,
+        lsb: 11,
+        msb: 29,
+        resolution: 180.0 / (1 << 18) as f32,
+        units: "deg",
+        min: -90.0,
+        max: 90.0,
+    },
+    LabelDef {
+        label: labels::LON,
+        equipment_id: 0,
+        format: LabelFormat::Bnr,
+        lsb: 11,
+        msb: 29,
+        resolution: 180.0 / (1 << 18) as f32,
+        units: "deg",
+        min: -180.0,
+        max: 180.0,
+    },
+    LabelDef {
+        label: labels::ALT,
+        equipment_id: 0,
+        format: LabelFormat::Bnr,
+        lsb: 11,
+        msb: 29,
+        resolution: 1.0,
+        units: "ft",
+        min: -1000.0,
+        max: 131071.0,
+    },
+    LabelDef {
+        label: labels::UTC,
+        equipment_id: 0,
+        format: LabelFormat::Bcd,
+        lsb: 11,
+        msb: 26,
+        resolution: 1.0,
+        units: "hhmm",
+        min: 0.0,
+        max: 2359.0,
+    },
+    LabelDef {
+        label: labels::DATE,
+        equipment_id: 0,
+        format: LabelFormat::Bcd,
+        lsb: 11,
+        msb: 26,
+        resolution: 1.0,
+        units: "ddmm",
+        min: 0.0,
+        max: 3112.0,
+    },
+];
+
+/// Runtime label dictionary mapping (label, equipment-id) to a decode spec.
+///
+/// HLR-A429-060: Declarative label decode
+///
+/// Pre-populated with [`STANDARD_LABELS`] via [`with_standard`](LabelDictionary::with_standard)
+/// and extensible at runtime with [`register`](LabelDictionary::register). Like
+/// the other tables in this module it has a fixed capacity `N` to avoid an
+/// allocator in `no_std`.
+pub struct LabelDictionary<const N: usize> {
+    defs: [Option<LabelDef>; N],
+}
+
+impl<const N: usize> LabelDictionary<N> {
+    /// Create an empty dictionary.
+    ///
+    /// LLR-A429-134: Dictionary construction
+    pub fn new() -> Self {
+        Self { defs: [None; N] }
+    }
+
+    /// Create a dictionary pre-populated with the standard labels.
+    ///
+    /// LLR-A429-135: Load the built-in label table
+    ///
+    /// Returns [`Arinc429Error::InvalidConfig`] if `N` is too small to hold the
+    /// standard table.
+    pub fn with_standard() -> Result<Self, Arinc429Error> {
+        let mut dict = Self::new();
+        for def in STANDARD_LABELS {
+            dict.register(*def)?;
+        }
+        Ok(dict)
+    }
+
+    /// Register (or replace) a label definition.
+    ///
+    /// LLR-A429-136: Runtime label registration
+    ///
+    /// Returns [`Arinc429Error::InvalidConfig`] if the table is full.
+    pub fn register(&mut self, def: LabelDef) -> Result<(), Arinc429Error> {
+        if let Some(slot) = self.find_slot(def.label, def.equipment_id) {
+            self.defs[slot] = Some(def);
+            return Ok(());
+        }
+        for slot in self.defs.iter_mut() {
+            if slot.is_none() {
+                *slot = Some(def);
+                return Ok(());
+            }
+        }
+        Err(Arinc429Error::InvalidConfig)
+    }
+
+    /// Look up a definition by label and equipment id.
+    ///
+    /// LLR-A429-137: Exact lookup
+    pub fn lookup(&self, label: u8, equipment_id: u8) -> Option<&LabelDef> {
+        self.find_slot(label, equipment_id)
+            .and_then(|slot| self.defs[slot].as_ref())
+    }
+
+    /// Decode a word by dispatching to the codec named in its label definition.
+    ///
+    /// LLR-A429-138: Dictionary-driven decode
+    ///
+    /// Resolves the definition by label (the first matching entry), decodes the
+    /// data field in engineering units, and reports validity from the SSM and
+    /// the configured range. Returns `None` for an unknown label.
+    pub fn decode_word(&self, word: &Word) -> Option<Decoded> {
+        let def = self
+            .defs
+            .iter()
+            .flatten()
+            .find(|d| d.label == word.label())?;
+
+        let data = word.data();
+        let offset = def.lsb - 11;
+        let len = def.msb - def.lsb + 1;
+
+        let ssm = word.ssm();
+
+        let value = match def.format {
+            LabelFormat::Bnr => {
+                // LLR-A429-031: BNR sign lives in the SSM, not the data field.
+                let magnitude = get_bits(data, offset, len) as f32 * def.resolution;
+                if BnrSsm::from_ssm(ssm) == BnrSsm::Minus {
+                    -magnitude
+                } else {
+                    magnitude
+                }
+            }
+            LabelFormat::Bcd => {
+                // Derive the digit count from the field width so no bits are
+                // discarded; the field is sized to a whole number of digits.
+                let digits = len / 4;
+                BcdFormat { digits }.decode(get_bits(data, offset, len)) as f32
+                    * def.resolution
+            }
+            LabelFormat::Discrete => get_bits(data, offset, len) as f32,
+        };
+
+        // For BNR the SSM carries the sign, so both Normal (Plus) and
+        // FailureWarning (Minus) are operational; for BCD/Discrete those bits
+        // are a genuine status, so only Normal is operational.
+        let operational = match def.format {
+            LabelFormat::Bnr => matches!(ssm, Ssm::Normal | Ssm::FailureWarning),
+            LabelFormat::Bcd | LabelFormat::Discrete => ssm == Ssm::Normal,
+        };
+        let valid = operational && value >= def.min && value <= def.max;
+
+        Some(Decoded {
+            value,
+            units: def.units,
+            ssm,
+            valid,
+        })
+    }
+
+    /// Slot index for a (label, equipment-id) pair, if present.
+    fn find_slot(&self, label: u8, equipment_id: u8) -> Option<usize> {
+        self.defs.iter().position(|slot| {
+            matches!(slot, Some(d) if d.label == label && d.equipment_id == equipment_id)
+        })
+    }
+}
+
+impl<const N: usize> Default for LabelDictionary<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A single self-test check outcome.
+///
+/// LLR-A429-140: Per-check BITE result
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CheckResult {
+    /// Label transmitted
+    pub label: u8,
+    /// Bus speed the word was sent at
+    pub speed: BusSpeed,
+    /// SSM transmitted
+    pub ssm: Ssm,
+    /// Raw word written to the bus
+    pub sent: u32,
+    /// Raw word read back in loopback
+    pub received: u32,
+    /// `true` when the readback matched byte-exact and parity verified
+    pub passed: bool,
+}
+
+/// Aggregate result of a [`SelfTest::run`] sweep.
+///
+/// LLR-A429-141: BITE summary report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelfTestReport {
+    /// Total checks executed
+    pub total: u16,
+    /// Checks that passed
+    pub passed: u16,
+    /// Checks that failed
+    pub failed: u16,
+    /// First failing check, if any
+    pub first_failure: Option<CheckResult>,
+}
+
+/// Fault the self-test harness can inject to exercise error handling.
+///
+/// LLR-A429-142: Fault-injection modes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FaultInjection {
+    /// Flip the parity bit so readback fails [`Arinc429Error::ParityError`]
+    FlipParity,
+    /// Corrupt a label bit (also caught by the parity guard)
+    CorruptLabel,
+    /// Queue two words in the RX FIFO without draining them and report
+    /// [`Arinc429Error::Overflow`] when both are waiting; the FIFO is not
+    /// filled to capacity
+    ForceOverflow,
+}
+
+/// Built-in test (BITE) loopback self-test harness.
+///
+/// HLR-A429-070: Power-on built-in test
+///
+/// Drives a hardware handle wired in loopback (TX output fed back to the RX
+/// input) through a deterministic sweep of labels, bus speeds, boundary data
+/// values, and SSM states, verifying byte-exact readback including parity. The
+/// [`inject`](SelfTest::inject) path deliberately corrupts a transmission so
+/// downstream handling of [`Arinc429Error`] can be exercised without real
+/// hardware.
+pub struct SelfTest<HW> {
+    hw: HW,
+}
+
+/// Boundary data-field values swept by the self-test.
+const SWEEP_DATA: [u32; 3] = [0x00000, 0x7FFFF, 0x2AAAA];
+/// SSM states swept by the self-test.
+const SWEEP_SSM: [Ssm; 4] = [
+    Ssm::Normal,
+    Ssm::NoComputedData,
+    Ssm::FunctionalTest,
+    Ssm::FailureWarning,
+];
+
+impl<HW: TxHardware + RxHardware> SelfTest<HW> {
+    /// Wrap a loopback-wired hardware handle.
+    ///
+    /// LLR-A429-143: Self-test construction
+    pub fn new(hw: HW) -> Self {
+        Self { hw }
+    }
+
+    /// Run the full sweep, reporting each check through `observer`.
+    ///
+    /// LLR-A429-144: Deterministic loopback sweep
+    ///
+    /// Every combination of the standard labels, both bus speeds, the boundary
+    /// data values, and each SSM state is transmitted and read back. A check
+    /// passes only if the readback is byte-exact and its parity verifies.
+    pub fn run(&mut self, observer: &mut dyn FnMut(&CheckResult)) -> SelfTestReport {
+        let mut report = SelfTestReport {
+            total: 0,
+            passed: 0,
+            failed: 0,
+            first_failure: None,
+        };
+
+        for speed in [BusSpeed::Low, BusSpeed::High] {
+            self.configure(speed);
+
+            for def in STANDARD_LABELS {
+                for &data in SWEEP_DATA.iter() {
+                    for &ssm in SWEEP_SSM.iter() {
+                        let word = Word::new(def.label, Sdi::All, data, ssm);
+                        let result = self.loopback(word, speed);
+
+                        report.total += 1;
+                        if result.passed {
+                            report.passed += 1;
+                        } else {
+                            report.failed += 1;
+                            if report.first_failure.is_none() {
+                                report.first_failure = Some(result);
+                            }
+                        }
+                        observer(&result);
+                    }
+                }
+            }
+        }
+
+        report
+    }
+
+    /// Inject a single fault and return the error downstream code should see.
+    ///
+    /// LLR-A429-145: Fault injection for error-path coverage
+    ///
+    /// Returns `Ok(())` only if the corruption was somehow not detected.
+    pub fn inject(&mut self, fault: FaultInjection) -> Result<(), Arinc429Error> {
+        self.configure(BusSpeed::High);
+        let base = Word::new(labels::ALT, Sdi::All, 0x2AAAA, Ssm::Normal);
+
+        match fault {
+            FaultInjection::FlipParity => {
+                // Toggling the parity bit breaks odd parity on readback.
+                self.hw.write_word(base.raw() ^ (1 << 31));
+                self.readback().map(|_| ())
+            }
+            FaultInjection::CorruptLabel => {
+                // A flipped label bit also violates the odd-parity guard.
+                self.hw.write_word(base.raw() ^ 0x01);
+                self.readback().map(|_| ())
+            }
+            FaultInjection::ForceOverflow => {
+                // Push two words without draining; more than one queued word
+                // is reported as an overflow. The words are left in the FIFO.
+                self.hw.write_word(base.raw());
+                self.hw.write_word(base.raw());
+                if self.hw.rx_fifo_count() > 1 {
+                    Err(Arinc429Error::Overflow)
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    /// Recover the hardware handle.
+    pub fn release(self) -> HW {
+        self.hw
+    }
+
+    /// Configure both directions for the given bus speed.
+    fn configure(&mut self, speed: BusSpeed) {
+        let _ = TxHardware::configure(&mut self.hw, &TxConfig { speed, gap_bits: 4 });
+        let _ = RxHardware::configure(
+            &mut self.hw,
+            &RxConfig {
+                speed,
+                label_filter: None,
+                sdi_filter: None,
+            },
+        );
+    }
+
+    /// Transmit a word and compare the loopback readback.
+    fn loopback(&mut self, word: Word, speed: BusSpeed) -> CheckResult {
+        self.hw.write_word(word.raw());
+        let received = self.readback();
+        let passed = matches!(received, Ok(w) if w.raw() == word.raw());
+
+        CheckResult {
+            label: word.label(),
+            speed,
+            ssm: word.ssm(),
+            sent: word.raw(),
+            received: received.map(|w| w.raw()).unwrap_or(0),
+            passed,
+        }
+    }
+
+    /// Read one word back, validating parity.
+    fn readback(&mut self) -> Result<Word, Arinc429Error> {
+        if !self.hw.rx_available() {
+            return Err(Arinc429Error::NotReady);
+        }
+        Word::from_raw(self.hw.read_word())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -481,13 +1521,196 @@ mod tests {
     fn test_bnr_encoding() {
         let format = BnrFormat {
             msb: 29,
+            lsb: 11,
             resolution: 0.01,
             signed: true,
         };
 
-        let encoded = format.encode(123.45);
-        let decoded = format.decode(encoded);
+        // Negative values carry their sign in the SSM, not the data field.
+        let (data, ssm) = format.encode(-123.45);
+        assert_eq!(ssm, BnrSsm::Minus.to_ssm());
+        let (decoded, flagged) = format.decode(data, ssm);
+        assert!((decoded + 123.45).abs() < 0.02, "BNR round-trip error too large");
+        assert!(!flagged, "a live value must not be flagged non-operational");
+
+        // Out-of-range magnitude clamps and flags no-computed-data.
+        let (_, ssm) = format.encode(1.0e9);
+        assert_eq!(ssm, Ssm::NoComputedData, "overflow must set NCD");
+    }
+
+    /// LLR-A429-113: Unit test for scheduler due-entry selection
+    #[test]
+    fn test_scheduler_due_order() {
+        let config = TxConfig {
+            speed: BusSpeed::Low,
+            gap_bits: 4,
+        };
+        let mut sched: TxScheduler<4> = TxScheduler::new(&config);
+
+        let fast = Word::new(labels::ALT, Sdi::All, 0, Ssm::Normal);
+        let slow = Word::new(labels::LAT, Sdi::All, 0, Ssm::Normal);
+        sched.schedule(fast, 20, 2, 0).unwrap();
+        sched.schedule(slow, 200, 8, 0).unwrap();
+
+        // Both are due at tick 0; the earliest-due scan finds one of them.
+        assert!(sched.earliest_due(0).is_some());
+        // Nothing is due before the first start tick.
+        sched.entries[0].as_mut().unwrap().next_due = 20;
+        sched.entries[1].as_mut().unwrap().next_due = 200;
+        assert!(sched.earliest_due(10).is_none());
+        assert_eq!(sched.earliest_due(20), Some(0));
+    }
+
+    /// LLR-A429-125: Unit test for cache freshness and rate tracking
+    #[test]
+    fn test_rx_cache_freshness() {
+        let mut cache: RxCache<4> = RxCache::new(100);
+        let word = Word::new(labels::ALT, Sdi::All, 0x1234, Ssm::Normal);
+
+        cache.update(word, 10).unwrap();
+        let fresh = cache.get(labels::ALT, Sdi::All, 50).unwrap();
+        assert!(fresh.fresh, "value within timeout should be fresh");
+        assert_eq!(fresh.age, 40);
+        assert_eq!(fresh.word.ssm(), Ssm::Normal);
+
+        // Past the timeout the SSM is downgraded.
+        let stale = cache.get(labels::ALT, Sdi::All, 200).unwrap();
+        assert!(!stale.fresh, "value past timeout should be stale");
+        assert_eq!(stale.word.ssm(), Ssm::NoComputedData);
+
+        // A second arrival exposes the inter-arrival interval.
+        assert_eq!(cache.interval(labels::ALT, Sdi::All), None);
+        cache.update(word, 35).unwrap();
+        assert_eq!(cache.interval(labels::ALT, Sdi::All), Some(25));
+    }
+
+    /// LLR-A429-138: Unit test for dictionary-driven decode
+    #[test]
+    fn test_label_dictionary_decode() {
+        let mut dict: LabelDictionary<16> = LabelDictionary::with_standard().unwrap();
+
+        // Standard BNR latitude decodes to degrees and is flagged valid.
+        let word = Word::new(labels::LAT, Sdi::All, 1000, Ssm::Normal);
+        let decoded = dict.decode_word(&word).expect("LAT is in the dictionary");
+        assert_eq!(decoded.units, "deg");
+        assert!(decoded.valid);
+        assert!((decoded.value - 1000.0 * (180.0 / 262144.0)).abs() < 1e-3);
+
+        // The sign comes from the SSM, not the data field: a Minus word
+        // (FailureWarning bits) decodes negative.
+        let neg = Word::new(labels::LAT, Sdi::All, 1000, Ssm::FailureWarning);
+        let decoded = dict.decode_word(&neg).expect("LAT is in the dictionary");
+        assert!(decoded.value < 0.0);
+
+        // Unknown labels have no definition.
+        let unknown = Word::new(0o001, Sdi::All, 0, Ssm::Normal);
+        assert!(dict.decode_word(&unknown).is_none());
+
+        // Custom labels can be registered at runtime.
+        let custom = LabelDef {
+            label: 0o001,
+            equipment_id: 0,
+            format: LabelFormat::Discrete,
+            lsb: 11,
+            msb: 14,
+            resolution: 1.0,
+            units: "flags",
+            min: 0.0,
+            max: 15.0,
+        };
+        dict.register(custom).unwrap();
+        assert!(dict.decode_word(&unknown).is_some());
+    }
+
+    /// LLR-A429-046: Unit test for discrete encode/decode and wire round-trip
+    #[test]
+    fn test_discrete_and_wire_order() {
+        let format = DiscreteFormat {
+            bit_offsets: [0, 3, 7],
+        };
+        let data = format.encode(&[true, false, true]);
+        let states: [bool; 3] = {
+            let mut it = format.decode(data);
+            [
+                it.next().unwrap(),
+                it.next().unwrap(),
+                it.next().unwrap(),
+            ]
+        };
+        assert_eq!(states, [true, false, true]);
+
+        // The wire bits round-trip through an exact reconstruction.
+        let word = Word::new(labels::ALT, Sdi::System1, data, Ssm::Normal);
+        let bits = word.to_wire_bits();
+        let restored = Word::from_wire_bits(&bits).expect("parity preserved");
+        assert_eq!(restored.raw(), word.raw());
+    }
+
+    /// Minimal loopback hardware: every transmitted word lands in a small FIFO
+    /// the receiver drains in order.
+    struct LoopbackHw {
+        fifo: [u32; 8],
+        head: usize,
+        len: usize,
+    }
+
+    impl LoopbackHw {
+        fn new() -> Self {
+            Self {
+                fifo: [0; 8],
+                head: 0,
+                len: 0,
+            }
+        }
+    }
 
-        assert!((decoded - 123.45).abs() < 0.02, "BNR round-trip error too large");
+    impl TxHardware for LoopbackHw {
+        fn configure(&mut self, _config: &TxConfig) -> Result<(), Arinc429Error> {
+            Ok(())
+        }
+        fn tx_ready(&self) -> bool {
+            true
+        }
+        fn write_word(&mut self, word: u32) {
+            if self.len < self.fifo.len() {
+                let tail = (self.head + self.len) % self.fifo.len();
+                self.fifo[tail] = word;
+                self.len += 1;
+            }
+        }
+    }
+
+    impl RxHardware for LoopbackHw {
+        fn configure(&mut self, _config: &RxConfig) -> Result<(), Arinc429Error> {
+            Ok(())
+        }
+        fn rx_available(&self) -> bool {
+            self.len > 0
+        }
+        fn read_word(&mut self) -> u32 {
+            let word = self.fifo[self.head];
+            self.head = (self.head + 1) % self.fifo.len();
+            self.len -= 1;
+            word
+        }
+        fn rx_fifo_count(&self) -> usize {
+            self.len
+        }
+    }
+
+    /// LLR-A429-144: Unit test for the BITE loopback sweep and fault injection
+    #[test]
+    fn test_self_test_loopback() {
+        let mut bite = SelfTest::new(LoopbackHw::new());
+
+        let mut seen = 0u16;
+        let report = bite.run(&mut |_check| seen += 1);
+        assert_eq!(report.total, seen, "observer sees every check");
+        assert_eq!(report.failed, 0, "clean loopback should pass every check");
+        assert!(report.first_failure.is_none());
+
+        // Injected faults surface as the expected errors.
+        assert_eq!(bite.inject(FaultInjection::FlipParity), Err(Arinc429Error::ParityError));
+        assert_eq!(bite.inject(FaultInjection::ForceOverflow), Err(Arinc429Error::Overflow));
     }
 }
@@ -9,8 +9,20 @@
 //! - SRS-IMU-003: IMU shall support sample rates up to 1kHz
 
 use core::fmt;
+use embedded_hal::delay::DelayNs;
 use embedded_hal::spi::SpiDevice;
 
+/// Monotonic microsecond time source
+///
+/// SRS-IMU-012: Timestamps shall come from a platform monotonic clock
+///
+/// Implemented by the host for whatever free-running counter is available
+/// (e.g. a SysTick- or TIM-backed microsecond counter).
+pub trait Clock {
+    /// Current value of the monotonic counter in microseconds
+    fn now_us(&self) -> u64;
+}
+
 /// HLR-IMU-001: Accelerometer full-scale range options
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum AccelRange {
@@ -50,6 +62,100 @@ pub enum OutputDataRate {
     Hz1000 = 3,
 }
 
+impl OutputDataRate {
+    /// LLR-IMU-004: Nominal sample period in microseconds for the selected ODR
+    ///
+    /// Used to back-date per-sample timestamps when draining the FIFO.
+    pub fn period_us(&self) -> u64 {
+        match self {
+            OutputDataRate::Hz100 => 10_000,
+            OutputDataRate::Hz200 => 5_000,
+            OutputDataRate::Hz500 => 2_000,
+            OutputDataRate::Hz1000 => 1_000,
+        }
+    }
+}
+
+/// HLR-IMU-004: Selects which sensors are queued into the hardware FIFO
+///
+/// Each enabled sensor contributes a fixed-size chunk to every FIFO frame,
+/// in the same order as the direct data-register burst (accel, gyro, then
+/// temperature).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FifoSensors {
+    /// Queue the 3-axis accelerometer (6 bytes per frame)
+    pub accel: bool,
+    /// Queue the 3-axis gyroscope (6 bytes per frame)
+    pub gyro: bool,
+    /// Queue the temperature sensor (2 bytes per frame)
+    pub temperature: bool,
+}
+
+impl Default for FifoSensors {
+    /// LLR-IMU-014: Default to batching the inertial sensors only
+    fn default() -> Self {
+        Self {
+            accel: true,
+            gyro: true,
+            temperature: false,
+        }
+    }
+}
+
+impl FifoSensors {
+    /// LLR-IMU-015: Size in bytes of a single packed FIFO frame
+    fn frame_size(&self) -> usize {
+        (self.accel as usize) * 6 + (self.gyro as usize) * 6 + (self.temperature as usize) * 2
+    }
+
+    /// LLR-IMU-016: FIFO_EN register mask selecting the queued sensors
+    fn enable_mask(&self) -> u8 {
+        let mut mask = 0;
+        if self.accel {
+            mask |= FIFO_EN_ACCEL;
+        }
+        if self.gyro {
+            mask |= FIFO_EN_GYRO;
+        }
+        if self.temperature {
+            mask |= FIFO_EN_TEMP;
+        }
+        mask
+    }
+}
+
+/// HLR-IMU-005: Auxiliary magnetometer sample rate
+///
+/// Mirrors the AK8963 continuous-measurement modes reachable over the IMU's
+/// internal I2C-master bridge.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MagSampleRate {
+    /// 8 Hz continuous measurement
+    Hz8 = 0,
+    /// 100 Hz continuous measurement
+    Hz100 = 1,
+}
+
+/// Auxiliary magnetometer configuration
+///
+/// LLR-IMU-019: Mag sampling and base sensitivity
+#[derive(Debug, Clone, Copy)]
+pub struct MagConfig {
+    /// Continuous-measurement sample rate
+    pub sample_rate: MagSampleRate,
+    /// Base sensitivity in microtesla per LSB (0.15 µT/LSB for 16-bit output)
+    pub sensitivity: f32,
+}
+
+impl Default for MagConfig {
+    fn default() -> Self {
+        Self {
+            sample_rate: MagSampleRate::Hz100,
+            sensitivity: 0.15,
+        }
+    }
+}
+
 /// IMU configuration structure
 ///
 /// LLR-IMU-010: Configuration shall be validated before applying
@@ -65,6 +171,12 @@ pub struct ImuConfig {
     pub lpf_enabled: bool,
     /// LLR-IMU-012: Low-pass filter cutoff (Hz)
     pub lpf_cutoff_hz: u16,
+    /// LLR-IMU-017: Enable the hardware sample FIFO
+    pub fifo_enabled: bool,
+    /// LLR-IMU-018: Which sensors are batched into the FIFO
+    pub fifo_sensors: FifoSensors,
+    /// LLR-IMU-019: Auxiliary magnetometer (9-axis) configuration, if present
+    pub mag: Option<MagConfig>,
 }
 
 impl Default for ImuConfig {
@@ -76,6 +188,9 @@ impl Default for ImuConfig {
             odr: OutputDataRate::Hz200,
             lpf_enabled: true,
             lpf_cutoff_hz: 50,
+            fifo_enabled: false,
+            fifo_sensors: FifoSensors::default(),
+            mag: None,
         }
     }
 }
@@ -99,6 +214,12 @@ pub struct ImuReading {
     pub gyro_z: i16,
     /// Temperature sensor (for compensation)
     pub temperature: i16,
+    /// X-axis magnetic field (raw ADC value)
+    pub mag_x: i16,
+    /// Y-axis magnetic field
+    pub mag_y: i16,
+    /// Z-axis magnetic field
+    pub mag_z: i16,
     /// SRS-IMU-010: Timestamp in microseconds
     pub timestamp_us: u64,
 }
@@ -122,10 +243,27 @@ pub struct ImuData {
     pub gyro_z: f32,
     /// Temperature in Celsius
     pub temperature_c: f32,
+    /// X magnetic field in microtesla
+    pub mag_x: f32,
+    /// Y magnetic field in microtesla
+    pub mag_y: f32,
+    /// Z magnetic field in microtesla
+    pub mag_z: f32,
     /// Timestamp in microseconds
     pub timestamp_us: u64,
 }
 
+/// Stored accelerometer/gyroscope zero-rate bias offsets
+///
+/// SRS-IMU-060: Static bias shall be removable and persistable
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ImuCalibration {
+    /// Per-axis accelerometer bias in raw LSB
+    pub accel_bias: [i16; 3],
+    /// Per-axis gyroscope bias in raw LSB
+    pub gyro_bias: [i16; 3],
+}
+
 /// IMU driver errors
 ///
 /// LLR-IMU-020: All error conditions shall be enumerated
@@ -143,6 +281,8 @@ pub enum ImuError {
     NotReady,
     /// Data overrun (missed samples)
     Overrun,
+    /// FIFO overflowed and dropped frames (LLR-IMU-021)
+    FifoOverflow,
 }
 
 impl fmt::Display for ImuError {
@@ -154,6 +294,7 @@ impl fmt::Display for ImuError {
             ImuError::InvalidConfig => write!(f, "Invalid configuration"),
             ImuError::NotReady => write!(f, "Sensor not ready"),
             ImuError::Overrun => write!(f, "Data overrun"),
+            ImuError::FifoOverflow => write!(f, "FIFO overflow"),
         }
     }
 }
@@ -161,27 +302,39 @@ impl fmt::Display for ImuError {
 /// IMU driver instance
 ///
 /// SRS-IMU-020: Driver shall manage single sensor instance
-pub struct Imu<SPI> {
+pub struct Imu<SPI, D, C> {
     spi: SPI,
+    delay: D,
+    clock: C,
     config: ImuConfig,
     accel_scale: f32,
     gyro_scale: f32,
+    /// Per-axis µT/LSB scale, folding the mag's factory sensitivity adjustment
+    mag_scale: [f32; 3],
+    calibration: ImuCalibration,
     initialized: bool,
 }
 
-impl<SPI, E> Imu<SPI>
+impl<SPI, D, C, E> Imu<SPI, D, C>
 where
     SPI: SpiDevice<Error = E>,
+    D: DelayNs,
+    C: Clock,
 {
     /// Create new IMU driver instance
     ///
     /// LLR-IMU-030: Constructor shall not access hardware
-    pub fn new(spi: SPI) -> Self {
+    /// SRS-IMU-012: Timing hooks are injected, not stubbed
+    pub fn new(spi: SPI, delay: D, clock: C) -> Self {
         Self {
             spi,
+            delay,
+            clock,
             config: ImuConfig::default(),
             accel_scale: 0.0,
             gyro_scale: 0.0,
+            mag_scale: [0.0; 3],
+            calibration: ImuCalibration::default(),
             initialized: false,
         }
     }
@@ -222,7 +375,14 @@ where
     /// Read raw sensor data
     ///
     /// SRS-IMU-040: Read shall be atomic (all axes from same sample)
+    /// SRS-IMU-060: Stored bias offsets are subtracted from every reading
     pub fn read_raw(&mut self) -> Result<ImuReading, ImuError> {
+        let mut reading = self.sample_raw()?;
+        self.apply_calibration(&mut reading);
+        Ok(reading)
+    }
+
+    fn sample_raw(&mut self) -> Result<ImuReading, ImuError> {
         if !self.initialized {
             return Err(ImuError::NotReady);
         }
@@ -239,11 +399,18 @@ where
             return Err(ImuError::Overrun);
         }
 
-        // LLR-IMU-052: Burst read all data registers
-        let mut buffer = [0u8; 14];
-        self.read_registers(REG_DATA_START, &mut buffer)?;
+        // LLR-IMU-052: Burst read all data registers. When the mag is enabled
+        // the external-sensor block (SLV0 output) trails the accel/gyro block,
+        // so a single burst captures all nine axes from the same instant.
+        let mut buffer = [0u8; 14 + MAG_DATA_LEN];
+        let len = if self.config.mag.is_some() {
+            buffer.len()
+        } else {
+            14
+        };
+        self.read_registers(REG_DATA_START, &mut buffer[..len])?;
 
-        Ok(ImuReading {
+        let mut reading = ImuReading {
             accel_x: i16::from_be_bytes([buffer[0], buffer[1]]),
             accel_y: i16::from_be_bytes([buffer[2], buffer[3]]),
             accel_z: i16::from_be_bytes([buffer[4], buffer[5]]),
@@ -252,7 +419,17 @@ where
             gyro_z: i16::from_be_bytes([buffer[10], buffer[11]]),
             temperature: i16::from_be_bytes([buffer[12], buffer[13]]),
             timestamp_us: self.get_timestamp(),
-        })
+            ..ImuReading::default()
+        };
+
+        // LLR-IMU-053: AK8963 reports little-endian samples in the ext block
+        if self.config.mag.is_some() {
+            reading.mag_x = i16::from_le_bytes([buffer[14], buffer[15]]);
+            reading.mag_y = i16::from_le_bytes([buffer[16], buffer[17]]);
+            reading.mag_z = i16::from_le_bytes([buffer[18], buffer[19]]);
+        }
+
+        Ok(reading)
     }
 
     /// Read and convert sensor data to engineering units
@@ -270,10 +447,180 @@ where
             gyro_y: raw.gyro_y as f32 * self.gyro_scale,
             gyro_z: raw.gyro_z as f32 * self.gyro_scale,
             temperature_c: self.convert_temperature(raw.temperature),
+            // LLR-IMU-061: Mag scale already folds the factory ASA adjustment
+            mag_x: raw.mag_x as f32 * self.mag_scale[0],
+            mag_y: raw.mag_y as f32 * self.mag_scale[1],
+            mag_z: raw.mag_z as f32 * self.mag_scale[2],
             timestamp_us: raw.timestamp_us,
         })
     }
 
+    /// Estimate and store static bias offsets with the device held still
+    ///
+    /// SRS-IMU-061: Calibration shall average a held-still burst
+    /// SAF-IMU-002: Calibration shall reject a moving device
+    ///
+    /// Averages `samples` raw readings: the mean gyro vector is taken as the
+    /// zero-rate bias, and accel bias is the mean minus the expected 1 g on
+    /// whichever axis is gravity-aligned. Returns `InvalidConfig` if the
+    /// sample variance indicates motion, so a moving device cannot poison the
+    /// bias. The computed offsets are stored and applied to later reads.
+    pub fn calibrate(&mut self, samples: u16) -> Result<ImuCalibration, ImuError> {
+        if !self.initialized || samples == 0 {
+            return Err(ImuError::NotReady);
+        }
+
+        let mut sum = [0i64; 6];
+        let mut sum_sq = [0i64; 6];
+        let mut collected = 0u16;
+        let mut attempts = 0u32;
+        let max_attempts = samples as u32 * 8 + 64;
+        while collected < samples {
+            match self.sample_raw() {
+                Ok(r) => {
+                    let axes = [
+                        r.accel_x, r.accel_y, r.accel_z, r.gyro_x, r.gyro_y, r.gyro_z,
+                    ];
+                    for (i, v) in axes.iter().enumerate() {
+                        sum[i] += *v as i64;
+                        sum_sq[i] += (*v as i64) * (*v as i64);
+                    }
+                    collected += 1;
+                }
+                // A sample not being ready yet just means we polled faster
+                // than the ODR; wait for the next one rather than aborting.
+                Err(ImuError::NotReady) | Err(ImuError::Overrun) => {
+                    attempts += 1;
+                    if attempts > max_attempts {
+                        return Err(ImuError::NotReady);
+                    }
+                    self.delay_ms(1);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        let n = samples as i64;
+        let mut mean = [0i64; 6];
+        for i in 0..6 {
+            // SAF-IMU-002: Reject if any axis variance exceeds the motion gate
+            let variance = (sum_sq[i] - sum[i] * sum[i] / n) / n;
+            if variance > MOTION_VARIANCE_THRESHOLD {
+                return Err(ImuError::InvalidConfig);
+            }
+            mean[i] = sum[i] / n;
+        }
+
+        // LLR-IMU-110: Remove expected gravity from the dominant accel axis
+        let gravity_lsb = self.accel_gravity_lsb() as i64;
+        let gravity_axis = (0..3)
+            .max_by_key(|&i| mean[i].abs())
+            .unwrap_or(2);
+        let mut accel_bias = [0i16; 3];
+        for i in 0..3 {
+            let mut bias = mean[i];
+            if i == gravity_axis {
+                bias -= mean[i].signum() * gravity_lsb;
+            }
+            accel_bias[i] = clamp_i16(bias);
+        }
+
+        let cal = ImuCalibration {
+            accel_bias,
+            gyro_bias: [clamp_i16(mean[3]), clamp_i16(mean[4]), clamp_i16(mean[5])],
+        };
+        self.calibration = cal;
+        Ok(cal)
+    }
+
+    /// Install previously stored calibration offsets
+    ///
+    /// SRS-IMU-062: Offsets may be reloaded from flash without recalibrating
+    pub fn set_calibration(&mut self, cal: ImuCalibration) {
+        self.calibration = cal;
+    }
+
+    /// Current calibration offsets
+    ///
+    /// SRS-IMU-062: Offsets may be persisted to flash
+    pub fn get_calibration(&self) -> ImuCalibration {
+        self.calibration
+    }
+
+    /// Number of bytes currently queued in the hardware FIFO
+    ///
+    /// SRS-IMU-041: FIFO fill level shall be observable
+    /// LLR-IMU-055: Read the 16-bit FIFO count register
+    pub fn fifo_count(&mut self) -> Result<u16, ImuError> {
+        let mut buffer = [0u8; 2];
+        self.read_registers(REG_FIFO_COUNT, &mut buffer)?;
+        Ok(u16::from_be_bytes(buffer) & FIFO_COUNT_MASK)
+    }
+
+    /// Flush the hardware FIFO, discarding any queued frames
+    ///
+    /// LLR-IMU-056: FIFO reset pulses the USER_CTRL reset bit
+    pub fn reset_fifo(&mut self) -> Result<(), ImuError> {
+        self.pulse_fifo_reset()
+    }
+
+    /// Drain batched samples from the hardware FIFO
+    ///
+    /// SRS-IMU-042: FIFO batch read shall not drop samples at 1 kHz
+    /// LLR-IMU-057: Decode as many packed frames as fit in `out`
+    ///
+    /// Reads the FIFO count, burst-reads the packed byte stream, and decodes
+    /// whole frames into `out`, returning how many readings were produced.
+    /// Per-sample timestamps are back-dated from the read instant using the
+    /// configured ODR period, so the most recent sample carries the read time
+    /// and earlier samples are spaced one period apart.
+    pub fn read_fifo(&mut self, out: &mut [ImuReading]) -> Result<usize, ImuError> {
+        if !self.initialized {
+            return Err(ImuError::NotReady);
+        }
+        if !self.config.fifo_enabled {
+            return Err(ImuError::InvalidConfig);
+        }
+
+        // LLR-IMU-058: Overflow is a hard loss of data; watermark is benign
+        let status = self.read_register(REG_FIFO_STATUS)?;
+        if status & FIFO_STATUS_OVERFLOW != 0 {
+            self.reset_fifo()?;
+            return Err(ImuError::FifoOverflow);
+        }
+
+        let frame_size = self.config.fifo_sensors.frame_size();
+        if frame_size == 0 {
+            return Ok(0);
+        }
+
+        // LLR-IMU-059: Only decode whole frames that fit the caller's buffer
+        // and a single burst transaction
+        let available = (self.fifo_count()? as usize) / frame_size;
+        let frames = available.min(out.len()).min(MAX_FIFO_BURST / frame_size);
+        if frames == 0 {
+            return Ok(0);
+        }
+
+        let mut buffer = [0u8; MAX_FIFO_BURST];
+        let byte_count = frames * frame_size;
+        self.read_registers(REG_FIFO_DATA, &mut buffer[..byte_count])?;
+
+        // Back-date from the read instant. The FIFO is first-in-first-out, so
+        // we drain the oldest `frames`; any samples still queued beyond the
+        // caller's buffer are newer, so age against `available`, not `frames`.
+        let now = self.get_timestamp();
+        let period = self.config.odr.period_us();
+        for (i, reading) in out[..frames].iter_mut().enumerate() {
+            let base = i * frame_size;
+            let frame = &buffer[base..base + frame_size];
+            let age = (available - 1 - i) as u64;
+            *reading = self.decode_fifo_frame(frame, now.saturating_sub(age * period));
+        }
+
+        Ok(frames)
+    }
+
     /// Enter low-power sleep mode
     ///
     /// SRS-IMU-050: Driver shall support low-power mode
@@ -305,6 +652,64 @@ where
         Ok(())
     }
 
+    fn apply_calibration(&self, reading: &mut ImuReading) {
+        // LLR-IMU-111: Saturating subtraction of the stored bias offsets
+        let cal = &self.calibration;
+        reading.accel_x = reading.accel_x.saturating_sub(cal.accel_bias[0]);
+        reading.accel_y = reading.accel_y.saturating_sub(cal.accel_bias[1]);
+        reading.accel_z = reading.accel_z.saturating_sub(cal.accel_bias[2]);
+        reading.gyro_x = reading.gyro_x.saturating_sub(cal.gyro_bias[0]);
+        reading.gyro_y = reading.gyro_y.saturating_sub(cal.gyro_bias[1]);
+        reading.gyro_z = reading.gyro_z.saturating_sub(cal.gyro_bias[2]);
+    }
+
+    fn accel_gravity_lsb(&self) -> i32 {
+        // LLR-IMU-112: Raw LSB count corresponding to 1 g at the current range
+        let range_g = match self.config.accel_range {
+            AccelRange::G2 => 2,
+            AccelRange::G4 => 4,
+            AccelRange::G8 => 8,
+            AccelRange::G16 => 16,
+        };
+        32768 / range_g
+    }
+
+    fn decode_fifo_frame(&self, frame: &[u8], timestamp_us: u64) -> ImuReading {
+        // LLR-IMU-060: Unpack a frame following the configured sensor order
+        let sensors = &self.config.fifo_sensors;
+        let mut reading = ImuReading {
+            timestamp_us,
+            ..ImuReading::default()
+        };
+        // Each frame follows the same field order as the direct burst read in
+        // `sample_raw`: accel, gyro, then temperature. Only the sensors
+        // actually present are debiased, so absent axes stay zero.
+        let cal = &self.calibration;
+        let mut offset = 0;
+        if sensors.accel {
+            reading.accel_x = i16::from_be_bytes([frame[offset], frame[offset + 1]])
+                .saturating_sub(cal.accel_bias[0]);
+            reading.accel_y = i16::from_be_bytes([frame[offset + 2], frame[offset + 3]])
+                .saturating_sub(cal.accel_bias[1]);
+            reading.accel_z = i16::from_be_bytes([frame[offset + 4], frame[offset + 5]])
+                .saturating_sub(cal.accel_bias[2]);
+            offset += 6;
+        }
+        if sensors.gyro {
+            reading.gyro_x = i16::from_be_bytes([frame[offset], frame[offset + 1]])
+                .saturating_sub(cal.gyro_bias[0]);
+            reading.gyro_y = i16::from_be_bytes([frame[offset + 2], frame[offset + 3]])
+                .saturating_sub(cal.gyro_bias[1]);
+            reading.gyro_z = i16::from_be_bytes([frame[offset + 4], frame[offset + 5]])
+                .saturating_sub(cal.gyro_bias[2]);
+            offset += 6;
+        }
+        if sensors.temperature {
+            reading.temperature = i16::from_be_bytes([frame[offset], frame[offset + 1]]);
+        }
+        reading
+    }
+
     fn run_self_test(&mut self) -> Result<bool, ImuError> {
         // LLR-IMU-080: Self-test procedure per datasheet
         self.write_register(REG_SELF_TEST, SELF_TEST_ENABLE)?;
@@ -335,6 +740,91 @@ where
             self.write_register(REG_LPF_CFG, lpf_cfg)?;
         }
 
+        // LLR-IMU-094: Configure the sample FIFO, flushing first so the
+        // buffer actually clears (FIFO_RESET only takes effect with FIFO_EN low)
+        if config.fifo_enabled {
+            self.write_register(REG_USER_CTRL, USER_CTRL_FIFO_RESET)?;
+            self.write_register(REG_FIFO_EN, config.fifo_sensors.enable_mask())?;
+        } else {
+            self.write_register(REG_FIFO_EN, 0)?;
+        }
+
+        // LLR-IMU-095: Bring up the steady-state USER_CTRL (FIFO + I2C master)
+        self.write_register(REG_USER_CTRL, user_ctrl_bits(config))?;
+
+        // LLR-IMU-096: Configure the auxiliary magnetometer over the bridge
+        if let Some(mag) = &config.mag {
+            self.configure_mag(mag)?;
+        }
+
+        Ok(())
+    }
+
+    fn pulse_fifo_reset(&mut self) -> Result<(), ImuError> {
+        // LLR-IMU-061: The FIFO_RESET bit only flushes when FIFO_EN is low,
+        // so drop the enable, pulse reset, then restore the steady-state bits.
+        self.write_register(REG_USER_CTRL, USER_CTRL_FIFO_RESET)?;
+        self.write_register(REG_USER_CTRL, user_ctrl_bits(&self.config))?;
+        Ok(())
+    }
+
+    fn configure_mag(&mut self, cfg: &MagConfig) -> Result<(), ImuError> {
+        // LLR-IMU-120: Enable the internal I2C master clock
+        self.write_register(REG_I2C_MST_CTRL, I2C_MST_CLK_400KHZ)?;
+
+        // LLR-IMU-121: Read the factory sensitivity-adjustment bytes from the
+        // mag's fuse ROM and fold them into the per-axis scale. ASA adjusts
+        // each axis as Hadj = H * ((ASA - 128) / 256 + 1).
+        self.mag_write(AK8963_REG_CNTL1, AK8963_MODE_POWER_DOWN)?;
+        self.delay_ms(1);
+        self.mag_write(AK8963_REG_CNTL1, AK8963_MODE_FUSE_ROM)?;
+        self.delay_ms(1);
+        let mut asa = [0u8; 3];
+        self.mag_read(AK8963_REG_ASAX, &mut asa)?;
+        for axis in 0..3 {
+            let adj = (asa[axis] as f32 - 128.0) / 256.0 + 1.0;
+            self.mag_scale[axis] = cfg.sensitivity * adj;
+        }
+
+        // LLR-IMU-122: Return to continuous measurement at the requested rate
+        self.mag_write(AK8963_REG_CNTL1, AK8963_MODE_POWER_DOWN)?;
+        self.delay_ms(1);
+        let mode = match cfg.sample_rate {
+            MagSampleRate::Hz8 => AK8963_MODE_CONT1,
+            MagSampleRate::Hz100 => AK8963_MODE_CONT2,
+        };
+        self.mag_write(AK8963_REG_CNTL1, AK8963_BIT_16 | mode)?;
+
+        // LLR-IMU-123: Point SLV0 at the mag data block so its samples land in
+        // the external-sensor registers and are burst-read atomically with the
+        // accel/gyro block. Read 7 bytes (HXL..ST2) so ST2 latches the next
+        // measurement per the AK8963 readout protocol.
+        self.write_register(REG_I2C_SLV0_ADDR, AK8963_I2C_ADDR | I2C_SLV_READ)?;
+        self.write_register(REG_I2C_SLV0_REG, AK8963_REG_HXL)?;
+        self.write_register(REG_I2C_SLV0_CTRL, I2C_SLV_EN | MAG_DATA_LEN as u8)?;
+
+        Ok(())
+    }
+
+    fn mag_write(&mut self, reg: u8, value: u8) -> Result<(), ImuError> {
+        // LLR-IMU-124: Single-byte write to the aux mag via SLV4
+        self.write_register(REG_I2C_SLV4_ADDR, AK8963_I2C_ADDR)?;
+        self.write_register(REG_I2C_SLV4_REG, reg)?;
+        self.write_register(REG_I2C_SLV4_DO, value)?;
+        self.write_register(REG_I2C_SLV4_CTRL, I2C_SLV_EN)?;
+        self.delay_ms(1);
+        Ok(())
+    }
+
+    fn mag_read(&mut self, reg: u8, buffer: &mut [u8]) -> Result<(), ImuError> {
+        // LLR-IMU-125: Sequential single-byte reads from the aux mag via SLV4
+        for (i, byte) in buffer.iter_mut().enumerate() {
+            self.write_register(REG_I2C_SLV4_ADDR, AK8963_I2C_ADDR | I2C_SLV_READ)?;
+            self.write_register(REG_I2C_SLV4_REG, reg + i as u8)?;
+            self.write_register(REG_I2C_SLV4_CTRL, I2C_SLV_EN)?;
+            self.delay_ms(1);
+            *byte = self.read_register(REG_I2C_SLV4_DI)?;
+        }
         Ok(())
     }
 
@@ -375,14 +865,163 @@ where
         }
     }
 
-    fn delay_ms(&self, _ms: u32) {
-        // Platform-specific delay
+    fn delay_ms(&mut self, ms: u32) {
+        // SRS-IMU-012: Use the injected embedded-hal delay
+        self.delay.delay_ms(ms);
     }
 
     fn get_timestamp(&self) -> u64 {
-        // Platform-specific timestamp
-        0
+        // SRS-IMU-012: Stamp from the injected monotonic clock
+        self.clock.now_us()
+    }
+}
+
+/// Quaternion attitude estimator driven by IMU data
+///
+/// SRS-IMU-070: Driver shall provide fused orientation output
+///
+/// Implements the Madgwick gradient-descent complementary filter, mirroring
+/// what sensor-hub parts like the EM7180 produce in hardware. The gyroscope
+/// integrates the orientation while the normalized accelerometer corrects
+/// drift along the gravity vector.
+#[derive(Debug, Clone, Copy)]
+pub struct AhrsFilter {
+    /// Orientation quaternion `[q0, q1, q2, q3]` (initialized to identity)
+    q: [f32; 4],
+    /// Filter gain trading gyro integration against accel correction
+    pub beta: f32,
+    /// Timestamp of the last `update_auto` sample, for automatic `dt`
+    last_timestamp_us: Option<u64>,
+}
+
+impl Default for AhrsFilter {
+    fn default() -> Self {
+        Self::new(0.1)
+    }
+}
+
+impl AhrsFilter {
+    /// Create a filter at identity orientation with the given gain
+    ///
+    /// LLR-IMU-130: Construction selects the Madgwick beta gain
+    pub fn new(beta: f32) -> Self {
+        Self {
+            q: [1.0, 0.0, 0.0, 0.0],
+            beta,
+            last_timestamp_us: None,
+        }
+    }
+
+    /// Advance the estimate by one gyro+accel sample over `dt` seconds
+    ///
+    /// LLR-IMU-131: Madgwick gradient-descent update
+    pub fn update(&mut self, data: &ImuData, dt: f32) {
+        let [mut q0, mut q1, mut q2, mut q3] = self.q;
+        let (gx, gy, gz) = (data.gyro_x, data.gyro_y, data.gyro_z);
+
+        // Gyro-driven rate of change: qDot = 0.5 * q ⊗ [0, gx, gy, gz]
+        let mut q_dot = [
+            0.5 * (-q1 * gx - q2 * gy - q3 * gz),
+            0.5 * (q0 * gx + q2 * gz - q3 * gy),
+            0.5 * (q0 * gy - q1 * gz + q3 * gx),
+            0.5 * (q0 * gz + q1 * gy - q2 * gx),
+        ];
+
+        // Only apply the accel correction when the vector is usable
+        let norm = (data.accel_x * data.accel_x
+            + data.accel_y * data.accel_y
+            + data.accel_z * data.accel_z)
+            .sqrt();
+        if norm > 0.0 {
+            let ax = data.accel_x / norm;
+            let ay = data.accel_y / norm;
+            let az = data.accel_z / norm;
+
+            // Gravity objective function and its gradient (step = Jᵀ f)
+            let f0 = 2.0 * (q1 * q3 - q0 * q2) - ax;
+            let f1 = 2.0 * (q0 * q1 + q2 * q3) - ay;
+            let f2 = 2.0 * (0.5 - q1 * q1 - q2 * q2) - az;
+
+            let mut s0 = -2.0 * q2 * f0 + 2.0 * q1 * f1;
+            let mut s1 = 2.0 * q3 * f0 + 2.0 * q0 * f1 - 4.0 * q1 * f2;
+            let mut s2 = -2.0 * q0 * f0 + 2.0 * q3 * f1 - 4.0 * q2 * f2;
+            let mut s3 = 2.0 * q1 * f0 + 2.0 * q2 * f1;
+
+            let s_norm = (s0 * s0 + s1 * s1 + s2 * s2 + s3 * s3).sqrt();
+            if s_norm > 0.0 {
+                s0 /= s_norm;
+                s1 /= s_norm;
+                s2 /= s_norm;
+                s3 /= s_norm;
+
+                // Subtract the beta-weighted correction from the gyro rate
+                q_dot[0] -= self.beta * s0;
+                q_dot[1] -= self.beta * s1;
+                q_dot[2] -= self.beta * s2;
+                q_dot[3] -= self.beta * s3;
+            }
+        }
+
+        // Integrate and renormalize
+        q0 += q_dot[0] * dt;
+        q1 += q_dot[1] * dt;
+        q2 += q_dot[2] * dt;
+        q3 += q_dot[3] * dt;
+
+        let q_norm = (q0 * q0 + q1 * q1 + q2 * q2 + q3 * q3).sqrt();
+        if q_norm > 0.0 {
+            self.q = [q0 / q_norm, q1 / q_norm, q2 / q_norm, q3 / q_norm];
+        }
+    }
+
+    /// Advance the estimate deriving `dt` from the reading timestamps
+    ///
+    /// LLR-IMU-132: Derive dt from `timestamp_us` deltas
+    ///
+    /// The first call after construction only latches the timestamp
+    /// and leaves the orientation unchanged.
+    pub fn update_auto(&mut self, data: &ImuData) {
+        if let Some(prev) = self.last_timestamp_us {
+            let dt = data.timestamp_us.saturating_sub(prev) as f32 / 1_000_000.0;
+            if dt > 0.0 {
+                self.update(data, dt);
+            }
+        }
+        self.last_timestamp_us = Some(data.timestamp_us);
     }
+
+    /// Raw orientation quaternion `[q0, q1, q2, q3]`
+    pub fn quaternion(&self) -> [f32; 4] {
+        self.q
+    }
+
+    /// Euler angles `(roll, pitch, yaw)` in radians
+    ///
+    /// LLR-IMU-133: Convert the quaternion to roll/pitch/yaw
+    pub fn euler(&self) -> (f32, f32, f32) {
+        let [q0, q1, q2, q3] = self.q;
+        let roll = (2.0 * (q0 * q1 + q2 * q3)).atan2(1.0 - 2.0 * (q1 * q1 + q2 * q2));
+        let pitch = (2.0 * (q0 * q2 - q3 * q1)).clamp(-1.0, 1.0).asin();
+        let yaw = (2.0 * (q0 * q3 + q1 * q2)).atan2(1.0 - 2.0 * (q2 * q2 + q3 * q3));
+        (roll, pitch, yaw)
+    }
+}
+
+/// Clamp a wide accumulator to the `i16` bias range instead of wrapping.
+fn clamp_i16(value: i64) -> i16 {
+    value.clamp(i16::MIN as i64, i16::MAX as i64) as i16
+}
+
+/// Steady-state USER_CTRL value for a configuration (FIFO + I2C master bits).
+fn user_ctrl_bits(config: &ImuConfig) -> u8 {
+    let mut bits = 0;
+    if config.fifo_enabled {
+        bits |= USER_CTRL_FIFO_EN;
+    }
+    if config.mag.is_some() {
+        bits |= USER_CTRL_I2C_MST_EN;
+    }
+    bits
 }
 
 // Register definitions
@@ -396,6 +1035,29 @@ const REG_GYRO_CFG: u8 = 0x1B;
 const REG_ODR_CFG: u8 = 0x19;
 const REG_LPF_CFG: u8 = 0x1A;
 const REG_SELF_TEST: u8 = 0x0D;
+const REG_FIFO_EN: u8 = 0x23;
+const REG_FIFO_STATUS: u8 = 0x3A;
+const REG_FIFO_COUNT: u8 = 0x72;
+const REG_FIFO_DATA: u8 = 0x74;
+const REG_USER_CTRL: u8 = 0x6A;
+const REG_I2C_MST_CTRL: u8 = 0x24;
+const REG_I2C_SLV0_ADDR: u8 = 0x25;
+const REG_I2C_SLV0_REG: u8 = 0x26;
+const REG_I2C_SLV0_CTRL: u8 = 0x27;
+const REG_I2C_SLV4_ADDR: u8 = 0x31;
+const REG_I2C_SLV4_REG: u8 = 0x32;
+const REG_I2C_SLV4_DO: u8 = 0x33;
+const REG_I2C_SLV4_CTRL: u8 = 0x34;
+const REG_I2C_SLV4_DI: u8 = 0x35;
+
+/// Largest FIFO burst drained in a single `read_fifo` transaction (bytes)
+const MAX_FIFO_BURST: usize = 504;
+
+/// Bytes read from the mag block per sample (HXL..HZH plus ST2)
+const MAG_DATA_LEN: usize = 7;
+
+/// Per-axis raw variance (LSB²) above which calibration assumes motion
+const MOTION_VARIANCE_THRESHOLD: i64 = 2500;
 
 const EXPECTED_DEVICE_ID: u8 = 0x71;
 const CTRL1_SOFT_RESET: u8 = 0x80;
@@ -404,3 +1066,170 @@ const STATUS_OVERRUN: u8 = 0x10;
 const PWR_SLEEP: u8 = 0x40;
 const SELF_TEST_ENABLE: u8 = 0x01;
 const SELF_TEST_PASS: u8 = 0x80;
+
+const FIFO_EN_ACCEL: u8 = 0x08;
+const FIFO_EN_GYRO: u8 = 0x70;
+const FIFO_EN_TEMP: u8 = 0x80;
+const FIFO_COUNT_MASK: u16 = 0x1FFF;
+const FIFO_STATUS_OVERFLOW: u8 = 0x10;
+const USER_CTRL_FIFO_EN: u8 = 0x40;
+const USER_CTRL_FIFO_RESET: u8 = 0x04;
+const USER_CTRL_I2C_MST_EN: u8 = 0x20;
+
+const I2C_MST_CLK_400KHZ: u8 = 0x0D;
+const I2C_SLV_EN: u8 = 0x80;
+const I2C_SLV_READ: u8 = 0x80;
+
+// AK8963 auxiliary magnetometer (reached over the internal I2C master)
+const AK8963_I2C_ADDR: u8 = 0x0C;
+const AK8963_REG_HXL: u8 = 0x03;
+const AK8963_REG_CNTL1: u8 = 0x0A;
+const AK8963_REG_ASAX: u8 = 0x10;
+const AK8963_MODE_POWER_DOWN: u8 = 0x00;
+const AK8963_MODE_CONT1: u8 = 0x02;
+const AK8963_MODE_CONT2: u8 = 0x06;
+const AK8963_MODE_FUSE_ROM: u8 = 0x0F;
+const AK8963_BIT_16: u8 = 0x10;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_hal::delay::DelayNs;
+    use embedded_hal::spi::{Error, ErrorKind, ErrorType, Operation, SpiDevice};
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl Error for MockError {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    /// SPI stub that answers register reads from a scripted FIFO frame. Read
+    /// commands carry the register in their high-bit-masked first byte; the
+    /// auto-increment burst used by `read_registers` leaves the register
+    /// pending for the following data transfer.
+    struct MockSpi {
+        frame: [u8; 14],
+        pending: Option<u8>,
+    }
+
+    impl MockSpi {
+        fn new(frame: [u8; 14]) -> Self {
+            Self {
+                frame,
+                pending: None,
+            }
+        }
+
+        fn reg_value(&self, reg: u8, index: usize) -> u8 {
+            match reg {
+                REG_FIFO_STATUS => 0,
+                REG_FIFO_COUNT => [0x00, 0x0E][index],
+                REG_FIFO_DATA => self.frame[index],
+                _ => 0,
+            }
+        }
+
+        fn fill(&mut self, buf: &mut [u8]) {
+            match buf.first() {
+                Some(&first) if first & 0x80 != 0 => {
+                    let reg = first & 0x7F;
+                    if buf.len() == 1 {
+                        // Auto-increment command; data arrives in the next transfer.
+                        self.pending = Some(reg);
+                    } else {
+                        // Combined single-register read: value follows the command.
+                        for (i, byte) in buf[1..].iter_mut().enumerate() {
+                            *byte = self.reg_value(reg, i);
+                        }
+                    }
+                }
+                _ => {
+                    if let Some(reg) = self.pending.take() {
+                        for (i, byte) in buf.iter_mut().enumerate() {
+                            *byte = self.reg_value(reg, i);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    impl ErrorType for MockSpi {
+        type Error = MockError;
+    }
+
+    impl SpiDevice for MockSpi {
+        fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), MockError> {
+            for op in operations {
+                match op {
+                    Operation::Read(buf) | Operation::TransferInPlace(buf) => self.fill(buf),
+                    Operation::Transfer(read, _) => self.fill(read),
+                    Operation::Write(_) | Operation::DelayNs(_) => {}
+                }
+            }
+            Ok(())
+        }
+    }
+
+    struct NoDelay;
+
+    impl DelayNs for NoDelay {
+        fn delay_ns(&mut self, _ns: u32) {}
+    }
+
+    struct FakeClock;
+
+    impl Clock for FakeClock {
+        fn now_us(&self) -> u64 {
+            1_000
+        }
+    }
+
+    fn be(value: i16) -> [u8; 2] {
+        value.to_be_bytes()
+    }
+
+    /// LLR-IMU-060: A temp-enabled FIFO frame decodes to the same axis order as
+    /// the direct burst read in `sample_raw` (accel, gyro, temperature).
+    #[test]
+    fn test_read_fifo_temp_frame_axis_order() {
+        let mut frame = [0u8; 14];
+        frame[0..2].copy_from_slice(&be(100)); // accel_x
+        frame[2..4].copy_from_slice(&be(200)); // accel_y
+        frame[4..6].copy_from_slice(&be(300)); // accel_z
+        frame[6..8].copy_from_slice(&be(400)); // gyro_x
+        frame[8..10].copy_from_slice(&be(500)); // gyro_y
+        frame[10..12].copy_from_slice(&be(600)); // gyro_z
+        frame[12..14].copy_from_slice(&be(700)); // temperature
+
+        let mut imu = Imu {
+            spi: MockSpi::new(frame),
+            delay: NoDelay,
+            clock: FakeClock,
+            config: ImuConfig {
+                fifo_enabled: true,
+                fifo_sensors: FifoSensors {
+                    accel: true,
+                    gyro: true,
+                    temperature: true,
+                },
+                ..ImuConfig::default()
+            },
+            accel_scale: 0.0,
+            gyro_scale: 0.0,
+            mag_scale: [0.0; 3],
+            calibration: ImuCalibration::default(),
+            initialized: true,
+        };
+
+        let mut out = [ImuReading::default(); 1];
+        let frames = imu.read_fifo(&mut out).unwrap();
+        assert_eq!(frames, 1);
+        assert_eq!(out[0].accel_x, 100);
+        assert_eq!(out[0].gyro_x, 400);
+        assert_eq!(out[0].temperature, 700);
+    }
+}
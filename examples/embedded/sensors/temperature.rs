@@ -7,8 +7,17 @@
 //! REQ-201: Driver shall provide 0.1C resolution
 //! REQ-202: Driver shall support multiple sensor instances
 
+use embedded_hal::delay::DelayNs;
 use embedded_hal::i2c::I2c;
 
+/// Monotonic microsecond time source
+///
+/// REQ-216: Timestamps shall come from a platform monotonic clock
+pub trait Clock {
+    /// Current value of the monotonic counter in microseconds
+    fn now_us(&self) -> u64;
+}
+
 /// REQ-203: Temperature conversion timeout
 const CONVERSION_TIMEOUT_MS: u32 = 100;
 
@@ -57,6 +66,12 @@ pub struct TempSensorConfig {
     pub alert_high_mc: i32,
     /// REQ-213: Alert threshold low (millidegrees)
     pub alert_low_mc: i32,
+    /// REQ-215: Enable SMBus Packet Error Checking (PEC)
+    pub crc_enabled: bool,
+    /// REQ-301: Alert de-assert hysteresis below the high threshold (millidegrees)
+    pub hysteresis_mc: i32,
+    /// REQ-302: Consecutive out-of-threshold readings before the alert latches (1/2/4/6)
+    pub fault_queue: u8,
 }
 
 impl Default for TempSensorConfig {
@@ -67,6 +82,9 @@ impl Default for TempSensorConfig {
             averaging: 4,
             alert_high_mc: 85_000,  // 85C
             alert_low_mc: -20_000,  // -20C
+            crc_enabled: false,
+            hysteresis_mc: 5_000,   // 5C, LM75-style OS hysteresis
+            fault_queue: 1,
         }
     }
 }
@@ -91,20 +109,30 @@ pub enum TempSensorError {
 /// Temperature sensor driver
 ///
 /// REQ-202: Support multiple instances
-pub struct TempSensor<I2C> {
+pub struct TempSensor<I2C, D, C> {
     i2c: I2C,
+    delay: D,
+    clock: C,
     config: TempSensorConfig,
     last_reading: TemperatureReading,
 }
 
-impl<I2C, E> TempSensor<I2C>
+impl<I2C, D, C, E> TempSensor<I2C, D, C>
 where
     I2C: I2c<Error = E>,
+    D: DelayNs,
+    C: Clock,
 {
     /// Create new sensor instance
     ///
     /// REQ-230: Constructor shall validate address
-    pub fn new(i2c: I2C, config: TempSensorConfig) -> Result<Self, TempSensorError> {
+    /// REQ-216: Timing hooks are injected, not stubbed
+    pub fn new(
+        i2c: I2C,
+        delay: D,
+        clock: C,
+        config: TempSensorConfig,
+    ) -> Result<Self, TempSensorError> {
         // REQ-230: Validate I2C address range
         if config.i2c_addr < 0x08 || config.i2c_addr > 0x77 {
             return Err(TempSensorError::NotPresent);
@@ -112,6 +140,8 @@ where
 
         Ok(Self {
             i2c,
+            delay,
+            clock,
             config,
             last_reading: TemperatureReading {
                 millidegrees_c: 0,
@@ -145,13 +175,20 @@ where
     /// Read current temperature
     ///
     /// REQ-240: Read shall complete within timeout
-    /// REQ-241: Read shall return cached value if sensor busy
+    /// REQ-203: Poll STATUS_BUSY until the conversion clears or the clock elapses
     pub fn read(&mut self) -> Result<TemperatureReading, TempSensorError> {
-        // REQ-242: Check sensor ready
-        let status = self.read_register(REG_STATUS)?;
-        if status & STATUS_BUSY != 0 {
-            // REQ-241: Return last valid reading
-            return Ok(self.last_reading);
+        // REQ-242/REQ-203: Wait for the conversion, bounded by the timeout.
+        let start_us = self.clock.now_us();
+        loop {
+            if self.read_register(REG_STATUS)? & STATUS_BUSY == 0 {
+                break;
+            }
+            if self.clock.now_us().saturating_sub(start_us)
+                >= CONVERSION_TIMEOUT_MS as u64 * 1_000
+            {
+                return Err(TempSensorError::Timeout);
+            }
+            self.delay.delay_ms(1);
         }
 
         // REQ-243: Read temperature registers
@@ -210,46 +247,291 @@ where
         Ok(())
     }
 
+    /// Borrow the sensor configuration.
+    ///
+    /// REQ-210: Configuration is immutable after init
+    pub fn config(&self) -> &TempSensorConfig {
+        &self.config
+    }
+
     // Private helpers
 
     fn read_register(&mut self, reg: u8) -> Result<u8, TempSensorError> {
-        let mut buf = [0u8];
-        self.i2c
-            .write_read(self.config.i2c_addr, &[reg], &mut buf)
-            .map_err(|_| TempSensorError::I2cError)?;
+        let mut buf = [0u8; 1];
+        self.read_registers(reg, &mut buf)?;
         Ok(buf[0])
     }
 
     fn read_registers(&mut self, reg: u8, buf: &mut [u8]) -> Result<(), TempSensorError> {
+        if !self.config.crc_enabled {
+            self.i2c
+                .write_read(self.config.i2c_addr, &[reg], buf)
+                .map_err(|_| TempSensorError::I2cError)?;
+            return Ok(());
+        }
+
+        // REQ-215: Clock one extra trailing PEC byte and verify it over the
+        // full transaction, including the address/command phase bytes.
+        let n = buf.len();
+        if n + 1 > SMBUS_BUF_LEN {
+            return Err(TempSensorError::I2cError);
+        }
+        let mut raw = [0u8; SMBUS_BUF_LEN];
         self.i2c
-            .write_read(self.config.i2c_addr, &[reg], buf)
+            .write_read(self.config.i2c_addr, &[reg], &mut raw[..n + 1])
             .map_err(|_| TempSensorError::I2cError)?;
+
+        let mut pec_input = [0u8; SMBUS_BUF_LEN + 3];
+        pec_input[0] = self.config.i2c_addr << 1; // write address + command phase
+        pec_input[1] = reg;
+        pec_input[2] = (self.config.i2c_addr << 1) | 1; // repeated-start read address
+        pec_input[3..3 + n].copy_from_slice(&raw[..n]);
+        if crc8(&pec_input[..3 + n]) != raw[n] {
+            return Err(TempSensorError::CrcError);
+        }
+
+        buf.copy_from_slice(&raw[..n]);
         Ok(())
     }
 
     fn write_register(&mut self, reg: u8, value: u8) -> Result<(), TempSensorError> {
-        self.i2c
-            .write(self.config.i2c_addr, &[reg, value])
-            .map_err(|_| TempSensorError::I2cError)?;
-        Ok(())
+        self.write_payload(&[reg, value])
     }
 
     fn write_threshold(&mut self, reg: u8, millidegrees: i32) -> Result<(), TempSensorError> {
         // REQ-280: Convert millidegrees to register format
         let raw = ((millidegrees * 128) / 1000) as i16;
         let bytes = raw.to_be_bytes();
+        self.write_payload(&[reg, bytes[0], bytes[1]])
+    }
+
+    /// Write a command/data payload, appending a PEC byte when enabled.
+    ///
+    /// REQ-215: Writes carry a computed PEC over the address and payload.
+    fn write_payload(&mut self, payload: &[u8]) -> Result<(), TempSensorError> {
+        if !self.config.crc_enabled {
+            return self
+                .i2c
+                .write(self.config.i2c_addr, payload)
+                .map_err(|_| TempSensorError::I2cError);
+        }
+
+        let n = payload.len();
+        if n + 2 > SMBUS_BUF_LEN {
+            return Err(TempSensorError::I2cError);
+        }
+        // PEC covers the write address followed by the payload bytes.
+        let mut pec_input = [0u8; SMBUS_BUF_LEN + 1];
+        pec_input[0] = self.config.i2c_addr << 1;
+        pec_input[1..1 + n].copy_from_slice(payload);
+        let pec = crc8(&pec_input[..1 + n]);
+
+        let mut frame = [0u8; SMBUS_BUF_LEN];
+        frame[..n].copy_from_slice(payload);
+        frame[n] = pec;
         self.i2c
-            .write(self.config.i2c_addr, &[reg, bytes[0], bytes[1]])
-            .map_err(|_| TempSensorError::I2cError)?;
-        Ok(())
+            .write(self.config.i2c_addr, &frame[..n + 1])
+            .map_err(|_| TempSensorError::I2cError)
     }
 
     fn get_timestamp(&self) -> u32 {
-        // Platform-specific
-        0
+        // REQ-216: Millisecond timestamp from the injected monotonic clock
+        (self.clock.now_us() / 1_000) as u32
+    }
+}
+
+/// Per-site debounce and latch state tracked by the [`ThermalManager`].
+///
+/// REQ-303: Alert debouncing shall be per-site
+#[derive(Debug, Clone, Copy)]
+struct SiteState {
+    /// Consecutive high-threshold breaches observed so far
+    breaches: u8,
+    /// Whether this site's comparator alert is currently latched
+    latched: bool,
+    /// Last valid reading for this site (millidegrees)
+    last_mc: i32,
+    /// Whether the most recent poll of this site produced a valid reading
+    valid: bool,
+}
+
+impl SiteState {
+    const fn new() -> Self {
+        Self {
+            breaches: 0,
+            latched: false,
+            last_mc: 0,
+            valid: false,
+        }
+    }
+
+    /// Apply one valid reading, updating the LM75-style comparator latch.
+    ///
+    /// REQ-301/REQ-302: Assert after `fault_queue` consecutive breaches of
+    /// `high`; de-assert only once the temperature drops below
+    /// `high - hysteresis`.
+    fn update(&mut self, mc: i32, high: i32, hysteresis: i32, fault_queue: u8) {
+        self.valid = true;
+        self.last_mc = mc;
+
+        if mc >= high {
+            self.breaches = self.breaches.saturating_add(1);
+            if self.breaches >= fault_queue.max(1) {
+                self.latched = true;
+            }
+        } else if mc < high - hysteresis {
+            // Below the hysteresis band: clear the latch and the counter.
+            self.breaches = 0;
+            self.latched = false;
+        } else {
+            // Inside the hysteresis band: hold the latch but stop counting.
+            self.breaches = 0;
+        }
     }
 }
 
+/// Aggregate thermal status across all managed sites.
+///
+/// REQ-305: Manager shall surface a single aggregate status
+#[derive(Debug, Clone, Copy)]
+pub struct ThermalStatus {
+    /// Hottest valid site temperature (millidegrees); `i32::MIN` if no site is valid
+    pub hottest_mc: i32,
+    /// Index of the hottest valid site
+    pub hottest_site: usize,
+    /// Running average across valid sites (millidegrees)
+    pub average_mc: i32,
+    /// Number of sites that produced a valid reading this poll
+    pub valid_sites: usize,
+    /// REQ-305: Aggregate alert, latched until the offending site cools
+    pub alert: bool,
+    /// Index of the site that asserted the alert, if any
+    pub alert_site: Option<usize>,
+}
+
+/// Multi-site thermal manager.
+///
+/// REQ-300: Manager shall aggregate up to `N` temperature sensors
+/// REQ-304: Manager shall expose hottest site, average, and per-site validity
+///
+/// Owns an array of [`TempSensor`] instances and debounces per-site alerts with
+/// LM75-style comparator behavior: an alert asserts only after `fault_queue`
+/// consecutive breaches of `alert_high_mc` and de-asserts once the temperature
+/// falls below `alert_high_mc - hysteresis_mc`.
+pub struct ThermalManager<I2C, D, C, const N: usize> {
+    sensors: [TempSensor<I2C, D, C>; N],
+    sites: [SiteState; N],
+}
+
+impl<I2C, D, C, E, const N: usize> ThermalManager<I2C, D, C, N>
+where
+    I2C: I2c<Error = E>,
+    D: DelayNs,
+    C: Clock,
+{
+    /// Create a manager over an array of sensors.
+    ///
+    /// REQ-300: Construct from an owned array of sensors
+    pub fn new(sensors: [TempSensor<I2C, D, C>; N]) -> Self {
+        Self {
+            sensors,
+            sites: [SiteState::new(); N],
+        }
+    }
+
+    /// Poll every site and recompute the aggregate status.
+    ///
+    /// REQ-306: A per-site read failure marks that site invalid without
+    /// aborting the sweep; the aggregate is computed over the valid sites.
+    pub fn poll(&mut self) -> ThermalStatus {
+        let mut hottest_mc = i32::MIN;
+        let mut hottest_site = 0;
+        let mut sum: i64 = 0;
+        let mut valid_sites = 0usize;
+        let mut alert_site = None;
+
+        for i in 0..N {
+            let reading = self.sensors[i].read();
+            let config = self.sensors[i].config();
+            let state = &mut self.sites[i];
+
+            match reading {
+                Ok(r) if r.valid => {
+                    // REQ-301/REQ-302: Debounce with the LM75-style fault queue.
+                    state.update(
+                        r.millidegrees_c,
+                        config.alert_high_mc,
+                        config.hysteresis_mc,
+                        config.fault_queue,
+                    );
+
+                    sum += r.millidegrees_c as i64;
+                    valid_sites += 1;
+                    if r.millidegrees_c > hottest_mc {
+                        hottest_mc = r.millidegrees_c;
+                        hottest_site = i;
+                    }
+                }
+                _ => {
+                    state.valid = false;
+                }
+            }
+
+            if state.latched && alert_site.is_none() {
+                alert_site = Some(i);
+            }
+        }
+
+        let average_mc = if valid_sites > 0 {
+            (sum / valid_sites as i64) as i32
+        } else {
+            0
+        };
+
+        ThermalStatus {
+            hottest_mc,
+            hottest_site,
+            average_mc,
+            valid_sites,
+            alert: alert_site.is_some(),
+            alert_site,
+        }
+    }
+
+    /// Whether the most recent poll of `site` produced a valid reading.
+    ///
+    /// REQ-304: Per-site validity shall be queryable
+    pub fn site_valid(&self, site: usize) -> bool {
+        self.sites.get(site).map(|s| s.valid).unwrap_or(false)
+    }
+
+    /// Borrow a managed sensor for direct access.
+    pub fn sensor(&mut self, site: usize) -> Option<&mut TempSensor<I2C, D, C>> {
+        self.sensors.get_mut(site)
+    }
+}
+
+/// Largest SMBus data payload handled in a single PEC-checked transaction.
+const SMBUS_BUF_LEN: usize = 8;
+
+/// Compute the SMBus PEC (CRC-8, polynomial 0x07, init 0x00, MSB-first).
+///
+/// REQ-215: PEC is taken over every byte of the transaction.
+fn crc8(data: &[u8]) -> u8 {
+    let mut crc = 0u8;
+    for &byte in data {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
 // Register definitions
 const REG_TEMP: u8 = 0x00;
 const REG_CONFIG: u8 = 0x01;
@@ -294,4 +576,44 @@ mod tests {
         assert!(!(-40_000..=125_000).contains(&-41_000));
         assert!(!(-40_000..=125_000).contains(&126_000));
     }
+
+    /// REQ-215: Unit test for SMBus PEC computation
+    #[test]
+    fn test_pec_crc8() {
+        // Empty transaction has a zero CRC with init 0x00.
+        assert_eq!(crc8(&[]), 0);
+
+        // Appending the PEC to the transaction zeroes the running CRC,
+        // which is how the receiver validates a PEC-protected frame.
+        let transaction = [0x90u8, 0x00, 0x91, 0x0C];
+        let pec = crc8(&transaction);
+        let mut checked = [0u8; 5];
+        checked[..4].copy_from_slice(&transaction);
+        checked[4] = pec;
+        assert_eq!(crc8(&checked), 0);
+    }
+
+    /// REQ-302: Alert latches only after `fault_queue` consecutive breaches
+    /// and REQ-301: de-asserts only below the hysteresis band.
+    #[test]
+    fn test_fault_queue_and_hysteresis() {
+        let high = 85_000;
+        let hyst = 5_000;
+        let mut site = SiteState::new();
+
+        // First breach with a fault queue of 2 must not latch yet.
+        site.update(high + 100, high, hyst, 2);
+        assert!(!site.latched);
+        // Second consecutive breach latches.
+        site.update(high + 100, high, hyst, 2);
+        assert!(site.latched);
+
+        // Inside the hysteresis band the latch holds.
+        site.update(high - 1_000, high, hyst, 2);
+        assert!(site.latched);
+
+        // Below the band the latch clears.
+        site.update(high - hyst - 1, high, hyst, 2);
+        assert!(!site.latched);
+    }
 }
@@ -19,6 +19,60 @@ pub struct CodeContext {
     pub text: String,
     /// Line number (1-indexed)
     pub line: usize,
+    /// Attributes, annotations, or decorators attached to this node
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<String>,
+    /// Parsed signature for function/type nodes, independent of formatting
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<Signature>,
+    /// Read/write sites of this context's named symbol within the file
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<Reference>,
+}
+
+/// Whether a reference reads or writes the symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ReferenceCategory {
+    /// The identifier is read (used)
+    Read,
+    /// The identifier is a binding or assignment target
+    Write,
+}
+
+/// A single use site of a named symbol.
+#[derive(Debug, Clone, Serialize)]
+pub struct Reference {
+    /// Line of the reference (1-indexed)
+    pub line: usize,
+    /// Whether the symbol is read or written at this site
+    pub category: ReferenceCategory,
+}
+
+/// A parsed view of a declaration's signature.
+///
+/// Populated from the tree-sitter fields so a requirement can be attached to a
+/// stable shape rather than to `first_line` text that shifts when the argument
+/// list is reformatted.
+#[derive(Debug, Clone, Serialize)]
+pub struct Signature {
+    /// Visibility modifier text (e.g. `pub`, `public`), if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<String>,
+    /// Generic/type-parameter clause text (e.g. `<T: Doc>`), if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generics: Option<String>,
+    /// Parameter texts, in order (functions/methods only)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<String>,
+    /// Return-type text, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_type: Option<String>,
+    /// Receiver/self parameter text for methods, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receiver: Option<String>,
+    /// Field or variant count for struct/enum/trait nodes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_count: Option<usize>,
 }
 
 /// Represents a scope item in the hierarchy chain.
@@ -33,9 +87,68 @@ pub struct ScopeItem {
     pub line: usize,
 }
 
+/// A single edge in a call hierarchy.
+///
+/// For an outgoing edge `caller_or_callee_name` is the callee; for an incoming
+/// edge it is the caller (the function enclosing the call site).
+#[derive(Debug, Clone, Serialize)]
+pub struct CallEdge {
+    /// Callee name (outgoing) or caller name (incoming).
+    pub caller_or_callee_name: String,
+    /// The AST node kind of the call site (e.g. "call_expression").
+    pub kind: String,
+    /// Line where the call site starts (1-indexed).
+    pub line: usize,
+}
+
+/// Incoming and outgoing call edges for the function enclosing a target line.
+///
+/// Resolution is name-only: overloads and methods that share a name collapse
+/// into the same symbol, so edges are matched by identifier text rather than by
+/// a resolved definition.
+#[derive(Debug, Clone, Serialize)]
+pub struct CallHierarchy {
+    /// Call sites that invoke the target function (callers).
+    pub incoming: Vec<CallEdge>,
+    /// Call sites made from within the target function (callees).
+    pub outgoing: Vec<CallEdge>,
+}
+
+/// Classification of a comment block's syntax.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CommentKind {
+    /// Ordinary line comment (`//`, `#`)
+    Line,
+    /// Ordinary block comment (`/* ... */`)
+    Block,
+    /// Documentation line comment (`///`, `//!`)
+    DocLine,
+    /// Documentation block comment (`/** ... */`, `/*! ... */`)
+    DocBlock,
+    /// Python-style triple-quoted docstring
+    Docstring,
+}
+
+/// A delimited comment block with its syntax stripped to clean prose.
+#[derive(Debug, Clone, Serialize)]
+pub struct CommentBlock {
+    /// Whether the block is a doc comment, docstring, or ordinary comment
+    pub kind: CommentKind,
+    /// The verbatim source lines of the block, joined with newlines
+    pub raw: String,
+    /// The block with comment syntax removed, joined with newlines
+    pub stripped: String,
+    /// First line of the block (1-indexed)
+    pub start_line: usize,
+    /// Last line of the block (1-indexed)
+    pub end_line: usize,
+}
+
 /// Context extracted for a comment block.
 #[derive(Debug, Clone)]
 pub struct BlockContext {
+    /// The comment block itself, classified and stripped
+    pub comment: CommentBlock,
     /// Code found above the comment block
     pub above: Option<CodeContext>,
     /// Code found below the comment block
@@ -110,6 +223,40 @@ const INTERESTING_KINDS: &[&str] = &[
     "expression_statement",
 ];
 
+/// Scope kinds that introduce a callable (a function, method, or closure).
+///
+/// A subset of [`SCOPE_KINDS`]; used to build the call-hierarchy symbol table.
+const FUNCTION_KINDS: &[&str] = &[
+    "function_item",
+    "function_definition",
+    "function_declaration",
+    "method_definition",
+    "method_declaration",
+    "arrow_function",
+    "lambda_expression",
+    "closure_expression",
+];
+
+/// Node kinds that represent a call site.
+const CALL_KINDS: &[&str] = &["call_expression", "method_call_expression", "macro_invocation"];
+
+/// Node kinds that represent an identifier reference.
+const IDENTIFIER_KINDS: &[&str] = &[
+    "identifier",
+    "field_identifier",
+    "type_identifier",
+    "shorthand_field_identifier",
+];
+
+/// Node kinds that represent an attribute, annotation, or decorator.
+const ATTRIBUTE_KINDS: &[&str] = &[
+    "attribute",
+    "attribute_item",
+    "annotation",
+    "marker_annotation",
+    "decorator",
+];
+
 /// Node kinds that represent scope containers.
 const SCOPE_KINDS: &[&str] = &[
     "function_item",
@@ -162,11 +309,15 @@ pub fn extract_block_context<D: Doc>(
         } else if is_interesting_kind(kind_str) {
             let name = extract_name(&node, kind_str);
             let text = first_line(node.text());
+            let attributes = extract_attributes(&node);
+            let signature = extract_signature(&node, kind_str);
             line_to_nodes.entry(start_line).or_default().push(NodeInfo {
                 kind: kind_str.to_string(),
                 name,
                 text,
                 priority: kind_priority(kind_str),
+                attributes,
+                signature,
             });
         }
     }
@@ -174,6 +325,9 @@ pub fn extract_block_context<D: Doc>(
     // Find the comment block boundaries by walking up and down
     let (block_start, block_end) = find_comment_block_bounds(comment_line, &comment_lines, source_lines);
 
+    // Classify the block and strip its comment syntax to clean prose
+    let comment = build_comment_block(block_start, block_end, source_lines);
+
     // Look for code ABOVE the block (first line with non-comment content)
     let above = find_context_above(block_start, &line_to_nodes, source_lines);
 
@@ -183,19 +337,133 @@ pub fn extract_block_context<D: Doc>(
     // Look for code on the same line as the comment (inline)
     let inline = find_inline_context(comment_line, &line_to_nodes);
 
+    // Annotate the resolved above/below symbols with their use sites in the file
+    let above = annotate_with_references(root, above, source_lines);
+    let below = annotate_with_references(root, below, source_lines);
+
     BlockContext {
+        comment,
         above,
         below,
         inline,
     }
 }
 
+/// Attach reference sites to a resolved context that names a symbol.
+fn annotate_with_references<D: Doc>(
+    root: &Node<D>,
+    ctx: Option<CodeContext>,
+    source_lines: &[&str],
+) -> Option<CodeContext> {
+    ctx.map(|mut c| {
+        if let Some(name) = c.name.clone() {
+            c.references = find_references(root, &name, source_lines);
+        }
+        c
+    })
+}
+
+/// Classify and strip a delimited comment block into clean prose.
+fn build_comment_block(
+    block_start: usize,
+    block_end: usize,
+    source_lines: &[&str],
+) -> CommentBlock {
+    let first = source_lines
+        .get(block_start)
+        .map(|s| s.trim())
+        .unwrap_or("");
+    let kind = classify_comment_kind(first);
+
+    let mut raw_lines = Vec::new();
+    let mut stripped_lines = Vec::new();
+    for line in block_start..=block_end {
+        if let Some(src) = source_lines.get(line) {
+            raw_lines.push(*src);
+            let cleaned = strip_comment_line(src, kind);
+            if !cleaned.is_empty() {
+                stripped_lines.push(cleaned);
+            }
+        }
+    }
+
+    CommentBlock {
+        kind,
+        raw: raw_lines.join("\n"),
+        stripped: stripped_lines.join("\n"),
+        start_line: block_start + 1,
+        end_line: block_end + 1,
+    }
+}
+
+/// Classify a comment block from the syntax of its first line.
+fn classify_comment_kind(first_line: &str) -> CommentKind {
+    let t = first_line.trim_start();
+    if t.starts_with("///") || t.starts_with("//!") {
+        CommentKind::DocLine
+    } else if t.starts_with("/**") || t.starts_with("/*!") {
+        CommentKind::DocBlock
+    } else if t.starts_with("/*") {
+        CommentKind::Block
+    } else if t.starts_with("\"\"\"") || t.starts_with("'''") {
+        // First-statement string in a body: a docstring (cf. expression_statement)
+        CommentKind::Docstring
+    } else {
+        // `//` line comments and `#` hash comments are ordinary lines
+        CommentKind::Line
+    }
+}
+
+/// Remove the comment syntax from a single line for the given block kind.
+fn strip_comment_line(line: &str, kind: CommentKind) -> String {
+    let mut s = line.trim();
+    match kind {
+        CommentKind::Line | CommentKind::DocLine => {
+            if let Some(r) = s.strip_prefix("///").or_else(|| s.strip_prefix("//!")) {
+                s = r;
+            } else if let Some(r) = s.strip_prefix("//") {
+                s = r;
+            } else if let Some(r) = s.strip_prefix('#') {
+                s = r;
+            }
+        }
+        CommentKind::Block | CommentKind::DocBlock => {
+            if let Some(r) = s
+                .strip_prefix("/**")
+                .or_else(|| s.strip_prefix("/*!"))
+                .or_else(|| s.strip_prefix("/*"))
+            {
+                s = r;
+            }
+            if let Some(r) = s.strip_suffix("*/") {
+                s = r;
+            }
+            s = s.trim();
+            // Continuation `*` on block-comment bodies
+            if let Some(r) = s.strip_prefix('*') {
+                s = r;
+            }
+        }
+        CommentKind::Docstring => {
+            if let Some(r) = s.strip_prefix("\"\"\"").or_else(|| s.strip_prefix("'''")) {
+                s = r;
+            }
+            if let Some(r) = s.strip_suffix("\"\"\"").or_else(|| s.strip_suffix("'''")) {
+                s = r;
+            }
+        }
+    }
+    s.trim().to_string()
+}
+
 #[derive(Debug)]
 struct NodeInfo {
     kind: String,
     name: Option<String>,
     text: String,
     priority: i32,
+    attributes: Vec<String>,
+    signature: Option<Signature>,
 }
 
 /// Find the boundaries of a comment block by walking up and down.
@@ -285,6 +553,9 @@ fn find_context_above(
                     name: best.name.clone(),
                     text: best.text.clone(),
                     line: line + 1, // 1-indexed
+                    attributes: best.attributes.clone(),
+                    signature: best.signature.clone(),
+                    references: Vec::new(),
                 });
             }
         }
@@ -304,6 +575,9 @@ fn find_context_above(
                     name: None,
                     text: trimmed.to_string(),
                     line: line + 1,
+                    attributes: Vec::new(),
+                    signature: None,
+                    references: Vec::new(),
                 });
             }
         }
@@ -333,6 +607,9 @@ fn find_context_below(
                     name: best.name.clone(),
                     text: best.text.clone(),
                     line: line + 1, // 1-indexed
+                    attributes: best.attributes.clone(),
+                    signature: best.signature.clone(),
+                    references: Vec::new(),
                 });
             }
         }
@@ -353,6 +630,9 @@ fn find_context_below(
                     name: None,
                     text: trimmed.to_string(),
                     line: line + 1,
+                    attributes: Vec::new(),
+                    signature: None,
+                    references: Vec::new(),
                 });
             }
         }
@@ -375,6 +655,9 @@ fn find_inline_context(
                 name: best.name.clone(),
                 text: best.text.clone(),
                 line: comment_line + 1, // 1-indexed
+                attributes: best.attributes.clone(),
+                signature: best.signature.clone(),
+                references: Vec::new(),
             });
         }
     }
@@ -412,6 +695,103 @@ pub fn extract_hierarchy<D: Doc>(root: &Node<D>, target_line: usize) -> Vec<Scop
     scopes
 }
 
+/// Trace the call hierarchy around the function enclosing `target_line`.
+///
+/// In a single DFS pass this builds (a) a symbol table of function/method
+/// ranges keyed by name and (b) the list of every call site with its callee
+/// name and enclosing function. Outgoing edges are the calls made from within
+/// the target function; incoming edges are the calls whose callee name matches
+/// the target function's name.
+///
+/// Resolution is name-only, so overloads and same-named methods on different
+/// types collapse together. A self-recursive call is reported on both sides but
+/// never duplicated within a side.
+pub fn extract_call_hierarchy<D: Doc>(root: &Node<D>, target_line: usize) -> CallHierarchy {
+    // (a) Function symbol ranges and (b) every call site, in one pass.
+    let mut functions: Vec<FnRange> = Vec::new();
+    let mut calls: Vec<CallSite> = Vec::new();
+
+    for node in root.dfs() {
+        let kind = node.kind();
+        let kind_str: &str = &kind;
+
+        if FUNCTION_KINDS.contains(&kind_str) {
+            if let Some(name) = extract_name(&node, kind_str) {
+                functions.push(FnRange {
+                    name,
+                    start_line: node.start_pos().line(),
+                    end_line: node.end_pos().line(),
+                });
+            }
+        } else if CALL_KINDS.contains(&kind_str) {
+            if let Some(callee) = extract_name(&node, kind_str) {
+                calls.push(CallSite {
+                    callee,
+                    kind: kind_str.to_string(),
+                    line: node.start_pos().line(),
+                });
+            }
+        }
+    }
+
+    // Resolve the enclosing function of each call with the same range test
+    // `extract_hierarchy` uses, keeping the innermost container.
+    let enclosing = |line: usize| -> Option<&FnRange> {
+        functions
+            .iter()
+            .filter(|f| f.start_line <= line && line <= f.end_line)
+            .max_by_key(|f| f.start_line)
+    };
+
+    let target_fn = enclosing(target_line);
+
+    let mut incoming = Vec::new();
+    let mut outgoing = Vec::new();
+    for call in &calls {
+        let call_fn = enclosing(call.line);
+
+        // Outgoing: the call is made from inside the target function.
+        if let (Some(target), Some(from)) = (target_fn, call_fn) {
+            if from.start_line == target.start_line && from.end_line == target.end_line {
+                outgoing.push(CallEdge {
+                    caller_or_callee_name: call.callee.clone(),
+                    kind: call.kind.clone(),
+                    line: call.line + 1,
+                });
+            }
+        }
+
+        // Incoming: the call targets the function enclosing `target_line`.
+        if let Some(target) = target_fn {
+            if call.callee == target.name {
+                if let Some(from) = call_fn {
+                    incoming.push(CallEdge {
+                        caller_or_callee_name: from.name.clone(),
+                        kind: call.kind.clone(),
+                        line: call.line + 1,
+                    });
+                }
+            }
+        }
+    }
+
+    CallHierarchy { incoming, outgoing }
+}
+
+/// Range of a callable in the source, keyed by its resolved name.
+struct FnRange {
+    name: String,
+    start_line: usize,
+    end_line: usize,
+}
+
+/// A call site with its resolved callee name.
+struct CallSite {
+    callee: String,
+    kind: String,
+    line: usize,
+}
+
 fn is_interesting_kind(kind: &str) -> bool {
     INTERESTING_KINDS.contains(&kind)
 }
@@ -544,6 +924,220 @@ fn extract_name<D: Doc>(node: &Node<D>, kind: &str) -> Option<String> {
     }
 }
 
+fn is_attribute_kind(kind: &str) -> bool {
+    ATTRIBUTE_KINDS.contains(&kind)
+}
+
+/// Find every read/write site of the symbol `name` within the file.
+///
+/// A single DFS collects identifier-kind nodes whose text equals `name`. A site
+/// is a [`ReferenceCategory::Write`] when the identifier is the designated
+/// binding/target field (`pattern`/`left`/`name`) of an enclosing
+/// `let_declaration`, `assignment`, or declarator node, and a
+/// [`ReferenceCategory::Read`] otherwise.
+pub fn find_references<D: Doc>(root: &Node<D>, name: &str, source_lines: &[&str]) -> Vec<Reference> {
+    let mut refs = Vec::new();
+
+    for node in root.dfs() {
+        let kind = node.kind();
+        let kind_str: &str = &kind;
+        if !IDENTIFIER_KINDS.contains(&kind_str) {
+            continue;
+        }
+
+        let text = node.text();
+        if text.as_ref() != name {
+            continue;
+        }
+
+        let line = node.start_pos().line();
+        if line >= source_lines.len() {
+            continue;
+        }
+
+        let category = if is_write_target(&node) {
+            ReferenceCategory::Write
+        } else {
+            ReferenceCategory::Read
+        };
+        refs.push(Reference {
+            line: line + 1,
+            category,
+        });
+    }
+
+    refs
+}
+
+/// Decide whether an identifier node is the binding/assignment target of its
+/// enclosing declaration, reusing the field names from [`extract_name`].
+fn is_write_target<D: Doc>(node: &Node<D>) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+    let kind = parent.kind();
+    let kind_str: &str = &kind;
+
+    let target = match kind_str {
+        "let_declaration" => parent.field("pattern"),
+        "assignment" | "assignment_expression" | "assignment_statement" => parent.field("left"),
+        "short_var_declaration" | "var_declaration" => parent.field("left"),
+        "variable_declarator" => parent.field("name"),
+        "field_declaration" | "local_variable_declaration" => {
+            parent.field("declarator").and_then(|d| d.field("name"))
+        }
+        _ => None,
+    };
+
+    match target {
+        Some(t) => t.range() == node.range(),
+        None => false,
+    }
+}
+
+/// Extract a parsed [`Signature`] for function/method and type-declaration nodes.
+fn extract_signature<D: Doc>(node: &Node<D>, kind: &str) -> Option<Signature> {
+    match kind {
+        "function_item" | "function_definition" | "function_declaration"
+        | "method_definition" | "method_declaration" => {
+            let params = node
+                .field("parameters")
+                .or_else(|| node.field("parameter_list"))
+                .map(|p| collect_params(&p))
+                .unwrap_or_default();
+            Some(Signature {
+                visibility: extract_visibility(node),
+                generics: node.field("type_parameters").map(|n| first_line(n.text())),
+                params,
+                return_type: node
+                    .field("return_type")
+                    .or_else(|| node.field("result"))
+                    .or_else(|| node.field("type"))
+                    .map(|n| first_line(n.text())),
+                receiver: extract_receiver(node),
+                member_count: None,
+            })
+        }
+
+        "struct_item" | "struct_definition" | "enum_item" | "enum_declaration"
+        | "trait_item" | "class_declaration" | "class_definition" | "interface_declaration" => {
+            Some(Signature {
+                visibility: extract_visibility(node),
+                generics: node.field("type_parameters").map(|n| first_line(n.text())),
+                params: Vec::new(),
+                return_type: None,
+                receiver: None,
+                member_count: Some(count_members(node)),
+            })
+        }
+
+        _ => None,
+    }
+}
+
+/// Read a leading visibility/access modifier (Rust `visibility_modifier`, Java
+/// `modifiers`) if one is present.
+fn extract_visibility<D: Doc>(node: &Node<D>) -> Option<String> {
+    for child in node.children() {
+        let kind = child.kind();
+        let kind_str: &str = &kind;
+        if kind_str == "visibility_modifier" || kind_str == "modifiers" {
+            return Some(first_line(child.text()));
+        }
+    }
+    None
+}
+
+/// Collect the individual parameter texts from a parameter list, skipping the
+/// surrounding punctuation tokens.
+fn collect_params<D: Doc>(params: &Node<D>) -> Vec<String> {
+    let mut out = Vec::new();
+    for child in params.children() {
+        let kind = child.kind();
+        let kind_str: &str = &kind;
+        if matches!(kind_str, "(" | ")" | "," | "|") || kind_str.is_empty() {
+            continue;
+        }
+        out.push(first_line(child.text()));
+    }
+    out
+}
+
+/// Extract the method receiver: Go keeps it in a `receiver` field, while Rust
+/// carries a `self` parameter inside the parameter list.
+fn extract_receiver<D: Doc>(node: &Node<D>) -> Option<String> {
+    if let Some(recv) = node.field("receiver") {
+        return Some(first_line(recv.text()));
+    }
+    let params = node
+        .field("parameters")
+        .or_else(|| node.field("parameter_list"))?;
+    for child in params.children() {
+        let kind = child.kind();
+        let kind_str: &str = &kind;
+        if kind_str == "self_parameter" {
+            return Some(first_line(child.text()));
+        }
+    }
+    None
+}
+
+/// Count the fields or variants declared in a struct/enum/trait body.
+fn count_members<D: Doc>(node: &Node<D>) -> usize {
+    let body = node.field("body").or_else(|| {
+        node.children().find(|c| {
+            let kind = c.kind();
+            let kind_str: &str = &kind;
+            kind_str.ends_with("_list") || kind_str.ends_with("body")
+        })
+    });
+    let Some(body) = body else {
+        return 0;
+    };
+    body.children()
+        .filter(|c| {
+            let kind = c.kind();
+            let kind_str: &str = &kind;
+            kind_str.ends_with("field_declaration")
+                || kind_str.ends_with("variant")
+                || kind_str == "enum_variant"
+                || kind_str.ends_with("_item")
+        })
+        .count()
+}
+
+/// Collect the attributes/annotations/decorators attached to a node.
+///
+/// Rust attaches `attribute_item` nodes as leading children of the item, while
+/// Java annotations and TypeScript/Python decorators sit on preceding siblings.
+/// Preceding siblings are walked outward until a non-attribute node is reached,
+/// and the results are returned in source order.
+fn extract_attributes<D: Doc>(node: &Node<D>) -> Vec<String> {
+    // Preceding siblings come back nearest-first; collect then restore order.
+    let mut leading = Vec::new();
+    for sibling in node.prev_all() {
+        let kind = sibling.kind();
+        let kind_str: &str = &kind;
+        if is_attribute_kind(kind_str) {
+            leading.push(first_line(sibling.text()));
+        } else {
+            break;
+        }
+    }
+    leading.reverse();
+
+    // Rust keeps the `#[...]` nodes as leading children of the item itself.
+    for child in node.children() {
+        let kind = child.kind();
+        let kind_str: &str = &kind;
+        if is_attribute_kind(kind_str) {
+            leading.push(first_line(child.text()));
+        }
+    }
+
+    leading
+}
+
 fn first_line(s: impl AsRef<str>) -> String {
     s.as_ref().lines().next().unwrap_or("").to_string()
 }
@@ -571,4 +1165,25 @@ mod tests {
         assert!(is_scope_kind("impl_item"));
         assert!(!is_scope_kind("let_declaration"));
     }
+
+    #[test]
+    fn test_classify_comment_kind() {
+        assert_eq!(classify_comment_kind("/// doc"), CommentKind::DocLine);
+        assert_eq!(classify_comment_kind("// plain"), CommentKind::Line);
+        assert_eq!(classify_comment_kind("# hash"), CommentKind::Line);
+        assert_eq!(classify_comment_kind("/** doc */"), CommentKind::DocBlock);
+        assert_eq!(classify_comment_kind("/* block"), CommentKind::Block);
+        assert_eq!(classify_comment_kind("\"\"\"docstring"), CommentKind::Docstring);
+    }
+
+    #[test]
+    fn test_strip_comment_line() {
+        assert_eq!(strip_comment_line("/// REQ-1", CommentKind::DocLine), "REQ-1");
+        assert_eq!(strip_comment_line("  # REQ-2", CommentKind::Line), "REQ-2");
+        assert_eq!(strip_comment_line(" * REQ-3", CommentKind::DocBlock), "REQ-3");
+        assert_eq!(
+            strip_comment_line("\"\"\"REQ-4\"\"\"", CommentKind::Docstring),
+            "REQ-4"
+        );
+    }
 }